@@ -0,0 +1,39 @@
+/* Generated from src/ffi.rs by `UPDATE_HEADER=1 cargo test ffi`, do not edit. */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define AOC_OK 0 /* ok */
+#define AOC_ERR_NULL_POINTER -1 /* null pointer argument */
+#define AOC_ERR_INVALID_UTF8 -2 /* input is not valid UTF-8 */
+#define AOC_ERR_UNKNOWN_DAY -3 /* no solution for this day */
+#define AOC_ERR_UNKNOWN_PART -4 /* part has to be 1 or 2 */
+#define AOC_ERR_PARSE -5 /* input could not be parsed */
+#define AOC_ERR_BUFFER_TOO_SMALL -6 /* output buffer is too small */
+#define AOC_ERR_PANIC -7 /* solver panicked */
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Writes the NUL-terminated answer to out_buf and its length to *out_len.
+   On AOC_ERR_BUFFER_TOO_SMALL *out_len is the capacity needed. */
+int32_t aoc_solve_year(uint16_t year, uint8_t day, uint8_t part,
+                       const uint8_t *input, size_t input_len,
+                       char *out_buf, size_t out_cap, size_t *out_len);
+
+/* aoc_solve_year for the latest year. */
+int32_t aoc_solve(uint8_t day, uint8_t part,
+                  const uint8_t *input, size_t input_len,
+                  char *out_buf, size_t out_cap, size_t *out_len);
+
+/* Static NUL-terminated description of an error code. */
+const char *aoc_error_message(int32_t code);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::utils::{self, ParseError};
+use crate::vault;
+
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.txt";
+const HASH_PREFIX: &str = "sha256";
+const SALT_BYTES: usize = 16;
+
+// an accepted answer that can be checked without being readable from the repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedAnswer {
+    salt: [u8; SALT_BYTES],
+    hash: [u8; 32],
+}
+
+impl HashedAnswer {
+    pub fn new(answer: &str) -> Self {
+        HashedAnswer::with_salt(vault::random_bytes(), answer)
+    }
+
+    fn with_salt(salt: [u8; SALT_BYTES], answer: &str) -> Self {
+        let hash = vault::sha256(&[&salt, answer.as_bytes()]);
+        HashedAnswer { salt, hash }
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        HashedAnswer::with_salt(self.salt, answer) == *self
+    }
+}
+
+// "sha256:SALT:HASH", or a plain answer which is hashed when the file is saved again
+impl FromStr for HashedAnswer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split(':').collect();
+        if fields.len() == 1 {
+            return Ok(HashedAnswer::new(s));
+        }
+        let invalid = || ParseError::new(format!("Invalid hashed answer: {:?}", s));
+        if fields.len() != 3 || fields[0] != HASH_PREFIX {
+            return Err(invalid());
+        }
+        let salt = vault::from_hex(fields[1]).and_then(|salt| salt.try_into().ok());
+        let hash = vault::from_hex(fields[2]).and_then(|hash| hash.try_into().ok());
+        match (salt, hash) {
+            (Some(salt), Some(hash)) => Ok(HashedAnswer { salt, hash }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for HashedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            HASH_PREFIX,
+            vault::to_hex(&self.salt),
+            vault::to_hex(&self.hash)
+        )
+    }
+}
+
+// one "YEAR DAY PART HASHED_ANSWER" line per accepted answer, `#` starts a comment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u16, u8, u8), HashedAnswer>,
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<_> = line.split_whitespace().collect();
+            if fields.len() != 4 {
+                return Err(ParseError::new(format!("Invalid answer line: {:?}", line)));
+            }
+            let key = (
+                utils::parse_number(fields[0])?,
+                utils::parse_number(fields[1])?,
+                utils::parse_number(fields[2])?,
+            );
+            answers.insert(key, fields[3].parse()?);
+        }
+
+        Ok(Answers { answers })
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((year, day, part), answer) in &self.answers {
+            writeln!(f, "{} {:02} {} {}", year, day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Answers {
+    // None when no answer was recorded for this part yet
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<bool> {
+        self.answers
+            .get(&(year, day, part))
+            .map(|hashed| hashed.matches(answer))
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str) {
+        self.answers
+            .insert((year, day, part), HashedAnswer::new(answer));
+    }
+
+    pub fn stars(&self, year: u16, day: u8) -> usize {
+        (1..=2)
+            .filter(|part| self.answers.contains_key(&(year, day, *part)))
+            .count()
+    }
+}
+
+pub struct AnswersFile {
+    path: PathBuf,
+}
+
+impl AnswersFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        AnswersFile { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // a missing file means no answers were recorded yet
+    pub fn load(&self) -> io::Result<Answers> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, answers: &Answers) -> io::Result<()> {
+        fs::write(&self.path, answers.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_round_trip() {
+        let answers: Answers = "# 2024\n2024 15 1 1457740\n2024 01 2 21306195\n"
+            .parse()
+            .unwrap();
+
+        assert_eq!(answers.check(2024, 15, 1, "1457740"), Some(true));
+        assert_eq!(answers.check(2024, 15, 1, "1457741"), Some(false));
+        assert_eq!(answers.check(2024, 15, 2, "1457740"), None);
+        assert_eq!(answers.stars(2024, 15), 1);
+        assert!(!answers.to_string().contains("1457740"));
+        assert_eq!(answers.to_string().parse::<Answers>(), Ok(answers));
+        assert!("2024 15 1".parse::<Answers>().is_err());
+        assert!("2024 15 1 sha256:00:00".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_answers_file() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        let file = AnswersFile::new(&path);
+        let _ = fs::remove_file(&path);
+
+        let mut answers = file.load().unwrap();
+        assert_eq!(answers, Answers::default());
+        answers.record(2024, 1, 1, "1651298");
+        file.save(&answers).unwrap();
+
+        assert_eq!(file.load().unwrap().stars(2024, 1), 1);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_CACHE_DIR: &str = ".aoc-cache";
+
+// FNV-1a, stable across platforms and compiler versions unlike `DefaultHasher`
+pub fn hash_input(input: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: u64,
+    pub version: String,
+}
+
+impl CacheKey {
+    pub fn new(year: u16, day: u8, part: u8, input: &str, version: &str) -> Self {
+        CacheKey {
+            year,
+            day,
+            part,
+            input_hash: hash_input(input.as_bytes()),
+            version: version.to_string(),
+        }
+    }
+
+    fn file_name(&self) -> String {
+        let version: String = self
+            .version
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        format!(
+            "{}-{:02}-{}-{:016x}-{}",
+            self.year, self.day, self.part, self.input_hash, version
+        )
+    }
+}
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<String> {
+        fs::read_to_string(self.dir.join(key.file_name())).ok()
+    }
+
+    pub fn put(&self, key: &CacheKey, answer: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(key.file_name()), answer)
+    }
+
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_file() {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> Cache {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir)
+    }
+
+    #[test]
+    fn test_hash_input() {
+        assert_eq!(hash_input(b""), 0xcbf29ce484222325);
+        assert_eq!(hash_input(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash_input(b"1 2"), hash_input(b"2 1"));
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let cache = temp_cache("round-trip");
+        let key = CacheKey::new(2024, 15, 1, "input", "1");
+
+        assert_eq!(cache.get(&key), None);
+        cache.put(&key, "1457740").unwrap();
+        assert_eq!(cache.get(&key), Some("1457740".to_string()));
+
+        // any change of the input, part or solver version misses the cache
+        assert_eq!(cache.get(&CacheKey::new(2024, 15, 1, "other", "1")), None);
+        assert_eq!(cache.get(&CacheKey::new(2024, 15, 2, "input", "1")), None);
+        assert_eq!(cache.get(&CacheKey::new(2024, 15, 1, "input", "2")), None);
+
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.clear().unwrap(), 0);
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
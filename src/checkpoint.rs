@@ -0,0 +1,95 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+// the state of a simulation, tagged with the day and solver version it belongs to
+#[derive(Debug, Serialize, Deserialize)]
+struct Checkpoint<T> {
+    year: u16,
+    day: u8,
+    version: String,
+    state: T,
+}
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+pub fn to_json<T: Serialize>(year: u16, day: u8, version: &str, state: &T) -> String {
+    let checkpoint = Checkpoint {
+        year,
+        day,
+        version: version.to_string(),
+        state,
+    };
+    serde_json::to_string(&checkpoint).expect("Simulation state is always serializable")
+}
+
+pub fn from_json<T: DeserializeOwned>(
+    year: u16,
+    day: u8,
+    version: &str,
+    json: &str,
+) -> io::Result<T> {
+    // the state is only decoded once the checkpoint is known to be for this day
+    let checkpoint: Checkpoint<serde_json::Value> = serde_json::from_str(json)
+        .map_err(|err| invalid_data(format!("Invalid checkpoint: {}", err)))?;
+    if (checkpoint.year, checkpoint.day) != (year, day) {
+        return Err(invalid_data(format!(
+            "Checkpoint is for {} day {}, not {} day {}",
+            checkpoint.year, checkpoint.day, year, day
+        )));
+    }
+    if checkpoint.version != version {
+        return Err(invalid_data(format!(
+            "Checkpoint is for solver version {}, not {}",
+            checkpoint.version, version
+        )));
+    }
+    serde_json::from_value(checkpoint.state)
+        .map_err(|err| invalid_data(format!("Invalid checkpoint state: {}", err)))
+}
+
+pub fn save<T: Serialize>(
+    path: &Path,
+    year: u16,
+    day: u8,
+    version: &str,
+    state: &T,
+) -> io::Result<()> {
+    fs::write(path, to_json(year, day, version, state) + "\n")
+}
+
+pub fn load<T: DeserializeOwned>(path: &Path, year: u16, day: u8, version: &str) -> io::Result<T> {
+    from_json(year, day, version, &fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let json = to_json(2024, 14, "1", &(vec![1, 2, 3], 42));
+
+        assert_eq!(
+            from_json(2024, 14, "1", &json).ok(),
+            Some((vec![1, 2, 3], 42))
+        );
+        assert!(from_json::<(Vec<i32>, i32)>(2024, 15, "1", &json).is_err());
+        assert!(from_json::<(Vec<i32>, i32)>(2024, 14, "2", &json).is_err());
+        assert!(from_json::<String>(2024, 14, "1", &json).is_err());
+    }
+
+    #[test]
+    fn test_checkpoint_file() {
+        let path = std::env::temp_dir().join(format!("aoc-checkpoint-{}", std::process::id()));
+        save(&path, 2024, 15, "1", &"state").unwrap();
+
+        assert_eq!(load::<String>(&path, 2024, 15, "1").unwrap(), "state");
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,445 @@
+use crate::config::ConfigArgs;
+
+pub const USAGE: &str = "Usage:
+    aoc [--year YEAR] [--day DAY] [--part PART] [--input PATH] [--no-cache] [--record]
+        [--trace PATH] [--alloc-stats]
+    aoc compare [--year YEAR] [--day DAY] [--part PART] [--input PATH] [--trace PATH]
+    aoc repl [--year YEAR] [--day DAY] [--input PATH]
+    aoc report [--year YEAR] [--output PATH]
+    aoc serve [--addr ADDR] [--max-body BYTES] [--timeout SECONDS]
+    aoc cache clear
+    aoc config show
+    aoc vault init | rotate
+    aoc vault add [--year YEAR] --day DAY PATH
+    aoc vault export [--year YEAR] --day DAY [--output PATH]
+    aoc leaderboard PATH
+
+Every command also takes [--config PATH] [--set KEY=VALUE]...";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SolveArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+    pub no_cache: bool,
+    pub record: bool,
+    pub trace: Option<String>,
+    pub alloc_stats: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ReportArgs {
+    pub year: Option<u16>,
+    pub output: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ServeArgs {
+    pub addr: Option<String>,
+    pub max_body_bytes: Option<usize>,
+    pub timeout_seconds: Option<u64>,
+}
+
+// `path` is the plain text input to add, or where to export it (stdout without it)
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct VaultArgs {
+    pub year: Option<u16>,
+    pub day: u8,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Solve(SolveArgs),
+    Compare(SolveArgs),
+    Repl(SolveArgs),
+    Report(ReportArgs),
+    Serve(ServeArgs),
+    CacheClear,
+    ConfigShow,
+    Leaderboard(String),
+    VaultInit,
+    VaultAdd(VaultArgs),
+    VaultRotate,
+    VaultExport(VaultArgs),
+}
+
+fn parse_flag_value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    match value {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid value for {}: {:?}", flag, value)),
+        None => Err(format!("Missing value for {}", flag)),
+    }
+}
+
+fn parse_solve_args<I>(mut args: I) -> Result<SolveArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut parsed = SolveArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => parsed.year = Some(parse_flag_value(&arg, args.next())?),
+            "--day" | "-d" => parsed.day = Some(parse_flag_value(&arg, args.next())?),
+            "--part" | "-p" => {
+                let part = parse_flag_value(&arg, args.next())?;
+                if part != 1 && part != 2 {
+                    return Err(format!("Part has to be 1 or 2, got {}", part));
+                }
+                parsed.part = Some(part);
+            }
+            "--input" | "-i" => parsed.input = Some(parse_flag_value(&arg, args.next())?),
+            "--no-cache" => parsed.no_cache = true,
+            "--record" => parsed.record = true,
+            "--trace" => parsed.trace = Some(parse_flag_value(&arg, args.next())?),
+            "--alloc-stats" => parsed.alloc_stats = true,
+            _ => return Err(format!("Unknown argument: {:?}", arg)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_report_args<I>(mut args: I) -> Result<ReportArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut parsed = ReportArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => parsed.year = Some(parse_flag_value(&arg, args.next())?),
+            "--output" | "-o" => parsed.output = Some(parse_flag_value(&arg, args.next())?),
+            _ => return Err(format!("Unknown argument: {:?}", arg)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_serve_args<I>(mut args: I) -> Result<ServeArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut parsed = ServeArgs::default();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => parsed.addr = Some(parse_flag_value(&arg, args.next())?),
+            "--max-body" => parsed.max_body_bytes = Some(parse_flag_value(&arg, args.next())?),
+            "--timeout" => parsed.timeout_seconds = Some(parse_flag_value(&arg, args.next())?),
+            _ => return Err(format!("Unknown argument: {:?}", arg)),
+        }
+    }
+
+    Ok(parsed)
+}
+
+// `add` takes the input as a positional argument, `export` an optional --output
+fn parse_vault_args<I>(mut args: I, positional_path: bool) -> Result<VaultArgs, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut year = None;
+    let mut day = None;
+    let mut path = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-y" => year = Some(parse_flag_value(&arg, args.next())?),
+            "--day" | "-d" => day = Some(parse_flag_value(&arg, args.next())?),
+            "--output" | "-o" if !positional_path => {
+                path = Some(parse_flag_value(&arg, args.next())?)
+            }
+            _ if positional_path && path.is_none() && !arg.starts_with('-') => path = Some(arg),
+            _ => return Err(format!("Unknown argument: {:?}", arg)),
+        }
+    }
+
+    let day = day.ok_or("Missing --day")?;
+    if positional_path && path.is_none() {
+        return Err("Missing input path".to_string());
+    }
+    Ok(VaultArgs { year, day, path })
+}
+
+// takes the options shared by all commands out of `args`, leaving the command itself
+pub fn split_config_args<I>(args: I) -> Result<(ConfigArgs, Vec<String>), String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut config_args = ConfigArgs::default();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_args.file = Some(parse_flag_value(&arg, args.next())?),
+            "--set" => {
+                let setting: String = parse_flag_value(&arg, args.next())?;
+                match setting.split_once('=') {
+                    Some((key, value)) => config_args
+                        .overrides
+                        .push((key.trim().to_string(), value.trim().to_string())),
+                    None => return Err(format!("Expected KEY=VALUE for --set, got {:?}", setting)),
+                }
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    Ok((config_args, rest))
+}
+
+pub fn parse_args<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(|arg| arg.as_str()) {
+        Some("cache") => {
+            args.next();
+            match args.next().as_deref() {
+                Some("clear") => Ok(Command::CacheClear),
+                Some(other) => Err(format!("Unknown cache command: {:?}", other)),
+                None => Err("Missing cache command".to_string()),
+            }
+        }
+        Some("compare") => {
+            args.next();
+            let parsed = parse_solve_args(args)?;
+            if parsed.no_cache || parsed.record || parsed.alloc_stats {
+                return Err(
+                    "compare only supports --year, --day, --part, --input and --trace".to_string(),
+                );
+            }
+            Ok(Command::Compare(parsed))
+        }
+        Some("repl") => {
+            args.next();
+            let parsed = parse_solve_args(args)?;
+            let defaults = SolveArgs {
+                year: parsed.year,
+                day: parsed.day,
+                input: parsed.input.clone(),
+                ..SolveArgs::default()
+            };
+            if parsed != defaults {
+                return Err("repl only supports --year, --day and --input".to_string());
+            }
+            Ok(Command::Repl(parsed))
+        }
+        Some("report") => {
+            args.next();
+            Ok(Command::Report(parse_report_args(args)?))
+        }
+        Some("serve") => {
+            args.next();
+            Ok(Command::Serve(parse_serve_args(args)?))
+        }
+        Some("config") => {
+            args.next();
+            match args.next().as_deref() {
+                Some("show") => Ok(Command::ConfigShow),
+                Some(other) => Err(format!("Unknown config command: {:?}", other)),
+                None => Err("Missing config command".to_string()),
+            }
+        }
+        Some("vault") => {
+            args.next();
+            match args.next().as_deref() {
+                Some("init") => match args.next() {
+                    None => Ok(Command::VaultInit),
+                    Some(extra) => Err(format!("Unknown argument: {:?}", extra)),
+                },
+                Some("rotate") => match args.next() {
+                    None => Ok(Command::VaultRotate),
+                    Some(extra) => Err(format!("Unknown argument: {:?}", extra)),
+                },
+                Some("add") => Ok(Command::VaultAdd(parse_vault_args(args, true)?)),
+                Some("export") => Ok(Command::VaultExport(parse_vault_args(args, false)?)),
+                Some(other) => Err(format!("Unknown vault command: {:?}", other)),
+                None => Err("Missing vault command".to_string()),
+            }
+        }
+        Some("leaderboard") => {
+            args.next();
+            match (args.next(), args.next()) {
+                (Some(path), None) => Ok(Command::Leaderboard(path)),
+                (None, _) => Err("Missing leaderboard export path".to_string()),
+                (Some(_), Some(extra)) => Err(format!("Unknown argument: {:?}", extra)),
+            }
+        }
+        _ => Ok(Command::Solve(parse_solve_args(args)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_flags() {
+        assert_eq!(
+            parse_args(args(&["--year", "2024", "-d", "15", "--part", "2"])),
+            Ok(Command::Solve(SolveArgs {
+                year: Some(2024),
+                day: Some(15),
+                part: Some(2),
+                input: None,
+                no_cache: false,
+                record: false,
+                trace: None,
+                alloc_stats: false,
+            }))
+        );
+        assert_eq!(
+            parse_args(args(&[])),
+            Ok(Command::Solve(SolveArgs::default()))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_flags() {
+        assert!(parse_args(args(&["--year"])).is_err());
+        assert!(parse_args(args(&["--day", "fifteen"])).is_err());
+        assert!(parse_args(args(&["--part", "3"])).is_err());
+        assert!(parse_args(args(&["--verbose"])).is_err());
+        assert!(parse_args(args(&["--trace"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_cache_commands() {
+        assert_eq!(
+            parse_args(args(&["cache", "clear"])),
+            Ok(Command::CacheClear)
+        );
+        assert!(parse_args(args(&["cache"])).is_err());
+        assert!(parse_args(args(&["cache", "drop"])).is_err());
+
+        match parse_args(args(&["-d", "6", "--no-cache"])) {
+            Ok(Command::Solve(solve_args)) => assert!(solve_args.no_cache),
+            other => panic!("Unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_compare_command() {
+        assert_eq!(
+            parse_args(args(&["compare", "-d", "13", "-p", "1"])),
+            Ok(Command::Compare(SolveArgs {
+                day: Some(13),
+                part: Some(1),
+                ..SolveArgs::default()
+            }))
+        );
+        assert!(parse_args(args(&["compare", "--no-cache"])).is_err());
+        assert!(parse_args(args(&["compare", "--record"])).is_err());
+        assert!(parse_args(args(&["compare", "--alloc-stats"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_repl_command() {
+        assert_eq!(
+            parse_args(args(&["repl", "--day", "15"])),
+            Ok(Command::Repl(SolveArgs {
+                day: Some(15),
+                ..SolveArgs::default()
+            }))
+        );
+        assert!(parse_args(args(&["repl", "-d", "15", "-p", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_report_command() {
+        assert_eq!(
+            parse_args(args(&["report", "-o", "README.md"])),
+            Ok(Command::Report(ReportArgs {
+                year: None,
+                output: Some("README.md".to_string()),
+            }))
+        );
+        assert!(parse_args(args(&["report", "--day", "1"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_serve_command() {
+        assert_eq!(
+            parse_args(args(&["serve", "--timeout", "5", "--max-body", "1024"])),
+            Ok(Command::Serve(ServeArgs {
+                addr: None,
+                max_body_bytes: Some(1024),
+                timeout_seconds: Some(5),
+            }))
+        );
+        assert!(parse_args(args(&["serve", "--timeout", "soon"])).is_err());
+    }
+
+    #[test]
+    fn test_split_config_args() {
+        let (config_args, rest) = split_config_args(args(&[
+            "-d",
+            "14",
+            "--set",
+            "params.2024.14.width=11",
+            "--config",
+            "a.toml",
+        ]))
+        .unwrap();
+
+        assert_eq!(config_args.file, Some("a.toml".to_string()));
+        assert_eq!(
+            config_args.overrides,
+            vec![("params.2024.14.width".to_string(), "11".to_string())]
+        );
+        assert_eq!(rest, args(&["-d", "14"]));
+        assert!(split_config_args(args(&["--set", "threads"])).is_err());
+        assert_eq!(
+            parse_args(args(&["config", "show"])),
+            Ok(Command::ConfigShow)
+        );
+    }
+
+    #[test]
+    fn test_parse_leaderboard_command() {
+        assert_eq!(
+            parse_args(args(&["leaderboard", "export.json"])),
+            Ok(Command::Leaderboard("export.json".to_string()))
+        );
+        assert!(parse_args(args(&["leaderboard"])).is_err());
+        assert!(parse_args(args(&["leaderboard", "a.json", "b.json"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_vault_commands() {
+        assert_eq!(parse_args(args(&["vault", "init"])), Ok(Command::VaultInit));
+        assert_eq!(
+            parse_args(args(&["vault", "add", "-d", "3", "input.txt"])),
+            Ok(Command::VaultAdd(VaultArgs {
+                year: None,
+                day: 3,
+                path: Some("input.txt".to_string()),
+            }))
+        );
+        assert_eq!(
+            parse_args(args(&["vault", "export", "-y", "2024", "-d", "3"])),
+            Ok(Command::VaultExport(VaultArgs {
+                year: Some(2024),
+                day: 3,
+                path: None,
+            }))
+        );
+        assert!(parse_args(args(&["vault", "add", "-d", "3"])).is_err());
+        assert!(parse_args(args(&["vault", "export", "input.txt"])).is_err());
+        assert!(parse_args(args(&["vault", "rotate", "now"])).is_err());
+        assert!(parse_args(args(&["vault"])).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant};
+
+use crate::registry::Solution;
+use crate::utils::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub name: &'static str,
+    pub answer: Result<String, ParseError>,
+    pub elapsed: Duration,
+}
+
+pub fn run_all(solution: &Solution, part: u8, input: &str) -> Vec<Run> {
+    solution
+        .implementations(part)
+        .into_iter()
+        .map(|(name, solver)| {
+            let start = Instant::now();
+            let answer = solver(input);
+            Run {
+                name,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect()
+}
+
+pub fn agree(runs: &[Run]) -> bool {
+    runs.windows(2).all(|pair| pair[0].answer == pair[1].answer)
+}
+
+// relative timings are given against the fastest implementation
+pub fn report(part: u8, runs: &[Run]) -> String {
+    let fastest = runs
+        .iter()
+        .map(|run| run.elapsed)
+        .min()
+        .unwrap_or_default()
+        .max(Duration::from_nanos(1));
+    let mut report = format!("Part {}:\n", part);
+
+    for run in runs {
+        let answer = match &run.answer {
+            Ok(answer) => answer.clone(),
+            Err(err) => format!("error: {}", err),
+        };
+        report.push_str(&format!(
+            "  {:<16} {:>20} {:>12.3?} {:>8.2}x\n",
+            run.name,
+            answer,
+            run.elapsed,
+            run.elapsed.as_secs_f64() / fastest.as_secs_f64()
+        ));
+    }
+    if !agree(runs) {
+        report.push_str("  implementations disagree!\n");
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Registry;
+
+    #[test]
+    fn test_alternatives_agree() {
+        let registry = Registry::new();
+        let input = std::fs::read_to_string("inputs/2024/input_10_test").unwrap();
+        let solution = registry.get(2024, 10).unwrap();
+
+        for part in 1..=2 {
+            let runs = run_all(solution, part, &input);
+            assert_eq!(runs.len(), 2);
+            assert!(agree(&runs));
+        }
+    }
+
+    #[test]
+    fn test_report_disagreement() {
+        let run = |name, answer: &str| Run {
+            name,
+            answer: Ok(answer.to_string()),
+            elapsed: Duration::from_millis(2),
+        };
+        let runs = vec![run("default", "36"), run("layered", "35")];
+
+        assert!(!agree(&runs));
+        assert!(report(1, &runs).ends_with("implementations disagree!\n"));
+        assert!(agree(&runs[..1]));
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::cache::DEFAULT_CACHE_DIR;
+use crate::params::Params;
+use crate::utils::{self, ParseError};
+
+pub const DEFAULT_CONFIG_FILE: &str = "aoc.toml";
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+pub const DEFAULT_VERBOSITY: u8 = 1;
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    Env(&'static str),
+    CommandLine,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+impl<T> Setting<T> {
+    fn default(value: T) -> Self {
+        Setting {
+            value,
+            source: Source::Default,
+        }
+    }
+
+    fn set(&mut self, value: T, source: Source) {
+        self.value = value;
+        self.source = source;
+    }
+}
+
+// the layout of aoc.toml, params are keyed by year and day: `[params.2024.14]`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    input_dir: Option<String>,
+    cache_dir: Option<String>,
+    vault_key_file: Option<String>,
+    verbosity: Option<u8>,
+    threads: Option<usize>,
+    #[serde(default)]
+    params: BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub file: Option<PathBuf>,
+    pub input_dir: Setting<String>,
+    pub cache_dir: Setting<String>,
+    pub vault_key_file: Setting<String>,
+    pub verbosity: Setting<u8>,
+    pub threads: Setting<usize>,
+    pub params: BTreeMap<(u16, u8), BTreeMap<String, Setting<String>>>,
+}
+
+// kept outside the repository, which the encrypted inputs may be committed to
+fn default_vault_key_file() -> String {
+    match std::env::var("HOME") {
+        Ok(home) => format!("{}/.config/aoc/vault.key", home),
+        Err(_) => ".aoc-vault.key".to_string(),
+    }
+}
+
+fn default_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1)
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            file: None,
+            input_dir: Setting::default(DEFAULT_INPUT_DIR.to_string()),
+            cache_dir: Setting::default(DEFAULT_CACHE_DIR.to_string()),
+            vault_key_file: Setting::default(default_vault_key_file()),
+            verbosity: Setting::default(DEFAULT_VERBOSITY),
+            threads: Setting::default(default_threads()),
+            params: BTreeMap::new(),
+        }
+    }
+}
+
+// overrides given on the command line, applied after the file and the environment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigArgs {
+    pub file: Option<String>,
+    pub overrides: Vec<(String, String)>,
+}
+
+fn param_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(value) => value.clone(),
+        other => other.to_string(),
+    }
+}
+
+impl Config {
+    // `key` is one of the top-level settings or `params.YEAR.DAY.NAME`
+    pub fn set(&mut self, key: &str, value: &str, source: Source) -> Result<(), ParseError> {
+        match key {
+            "input_dir" => self.input_dir.set(value.to_string(), source),
+            "cache_dir" => self.cache_dir.set(value.to_string(), source),
+            "vault_key_file" => self.vault_key_file.set(value.to_string(), source),
+            "verbosity" => self.verbosity.set(utils::parse_number(value)?, source),
+            "threads" => {
+                let threads = utils::parse_number(value)?;
+                if threads == 0 {
+                    return Err(ParseError::new("threads has to be at least 1"));
+                }
+                self.threads.set(threads, source)
+            }
+            _ => {
+                let fields: Vec<_> = key.splitn(4, '.').collect();
+                if fields.len() != 4 || fields[0] != "params" {
+                    return Err(ParseError::new(format!("Unknown setting: {:?}", key)));
+                }
+                let year = utils::parse_number(fields[1])?;
+                let day = utils::parse_number(fields[2])?;
+                let setting = Setting {
+                    value: value.to_string(),
+                    source,
+                };
+                self.params
+                    .entry((year, day))
+                    .or_default()
+                    .insert(fields[3].to_string(), setting);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_file(&mut self, content: &str, source: Source) -> Result<(), ParseError> {
+        let file: FileConfig = toml::from_str(content)
+            .map_err(|err| ParseError::new(format!("Invalid config: {}", err)))?;
+
+        let settings = [
+            ("input_dir", file.input_dir),
+            ("cache_dir", file.cache_dir),
+            ("vault_key_file", file.vault_key_file),
+            ("verbosity", file.verbosity.map(|v| v.to_string())),
+            ("threads", file.threads.map(|v| v.to_string())),
+        ];
+        for (key, value) in settings {
+            if let Some(value) = value {
+                self.set(key, &value, source.clone())?;
+            }
+        }
+
+        for (year, days) in file.params {
+            for (day, params) in days {
+                for (name, value) in params {
+                    let key = format!("params.{}.{}.{}", year, day, name);
+                    self.set(&key, &param_value(&value), source.clone())?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_env(&mut self, env: &impl Fn(&str) -> Option<String>) -> Result<(), ParseError> {
+        let variables = [
+            ("AOC_INPUT_DIR", "input_dir"),
+            ("AOC_CACHE_DIR", "cache_dir"),
+            ("AOC_VAULT_KEY_FILE", "vault_key_file"),
+            ("AOC_VERBOSITY", "verbosity"),
+            ("AOC_THREADS", "threads"),
+        ];
+        for (variable, key) in variables {
+            if let Some(value) = env(variable) {
+                self.set(key, &value, Source::Env(variable))
+                    .map_err(|err| ParseError::new(format!("{}: {}", variable, err)))?;
+            }
+        }
+        Ok(())
+    }
+
+    // defaults, then the config file, then environment variables, then command line overrides
+    pub fn resolve(
+        args: &ConfigArgs,
+        env: impl Fn(&str) -> Option<String>,
+        read_file: impl Fn(&str) -> io::Result<String>,
+    ) -> Result<Config, ParseError> {
+        let mut config = Config::default();
+
+        let (path, required) = match (&args.file, env(CONFIG_ENV)) {
+            (Some(path), _) => (path.clone(), true),
+            (None, Some(path)) => (path, true),
+            (None, None) => (DEFAULT_CONFIG_FILE.to_string(), false),
+        };
+        match read_file(&path) {
+            Ok(content) => {
+                let source = Source::File(PathBuf::from(&path));
+                config
+                    .apply_file(&content, source)
+                    .map_err(|err| ParseError::new(format!("{}: {}", path, err)))?;
+                config.file = Some(PathBuf::from(path));
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => (),
+            Err(err) => return Err(ParseError::new(format!("Cannot read {}: {}", path, err))),
+        }
+
+        config.apply_env(&env)?;
+
+        for (key, value) in &args.overrides {
+            config.set(key, value, Source::CommandLine)?;
+        }
+        Ok(config)
+    }
+
+    pub fn load(args: &ConfigArgs) -> Result<Config, ParseError> {
+        Config::resolve(
+            args,
+            |name| std::env::var(name).ok(),
+            |path| fs::read_to_string(path),
+        )
+    }
+
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        let mut params = Params::new();
+        params.set("threads", self.threads.value.to_string());
+        if let Some(overrides) = self.params.get(&(year, day)) {
+            for (name, setting) in overrides {
+                params.set(name.as_str(), setting.value.as_str());
+            }
+        }
+        params
+    }
+
+    // the effective configuration in aoc.toml syntax with the source of every value
+    pub fn show(&self) -> String {
+        let mut lines = Vec::new();
+        match &self.file {
+            Some(path) => lines.push(format!("# config file: {}", path.display())),
+            None => lines.push("# config file: none".to_string()),
+        }
+
+        let settings = [
+            (
+                "input_dir",
+                format!("{:?}", self.input_dir.value),
+                &self.input_dir.source,
+            ),
+            (
+                "cache_dir",
+                format!("{:?}", self.cache_dir.value),
+                &self.cache_dir.source,
+            ),
+            (
+                "vault_key_file",
+                format!("{:?}", self.vault_key_file.value),
+                &self.vault_key_file.source,
+            ),
+            (
+                "verbosity",
+                self.verbosity.value.to_string(),
+                &self.verbosity.source,
+            ),
+            (
+                "threads",
+                self.threads.value.to_string(),
+                &self.threads.source,
+            ),
+        ];
+        for (key, value, source) in settings {
+            lines.push(format!("{} = {}  # {}", key, value, source));
+        }
+
+        for ((year, day), params) in &self.params {
+            lines.push(format!("\n[params.{}.{}]", year, day));
+            for (name, setting) in params {
+                lines.push(format!(
+                    "{} = {:?}  # {}",
+                    name, setting.value, setting.source
+                ));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+input_dir = "my-inputs"
+verbosity = 2
+
+[params.2024.14]
+width = 11
+height = 7
+"#;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    fn config_file(path: &str) -> io::Result<String> {
+        match path {
+            DEFAULT_CONFIG_FILE => Ok(CONFIG.to_string()),
+            _ => Err(io::Error::from(io::ErrorKind::NotFound)),
+        }
+    }
+
+    #[test]
+    fn test_defaults_without_file() {
+        let config = Config::resolve(&ConfigArgs::default(), no_env, |_| {
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        })
+        .unwrap();
+
+        assert_eq!(config.file, None);
+        assert_eq!(config.input_dir, Setting::default("inputs".to_string()));
+        assert_eq!(config.cache_dir.value, ".aoc-cache");
+        assert_eq!(config.verbosity.value, 1);
+        assert!(config.params(2024, 14).get("width").is_none());
+    }
+
+    #[test]
+    fn test_override_order() {
+        let env = |name: &str| match name {
+            "AOC_VERBOSITY" => Some("0".to_string()),
+            "AOC_THREADS" => Some("3".to_string()),
+            "AOC_VAULT_KEY_FILE" => Some("/keys/aoc".to_string()),
+            _ => None,
+        };
+        let args = ConfigArgs {
+            file: None,
+            overrides: vec![
+                ("threads".to_string(), "2".to_string()),
+                ("params.2024.14.width".to_string(), "13".to_string()),
+            ],
+        };
+        let config = Config::resolve(&args, env, config_file).unwrap();
+        let file = Source::File(PathBuf::from(DEFAULT_CONFIG_FILE));
+
+        assert_eq!(config.input_dir.value, "my-inputs");
+        assert_eq!(config.input_dir.source, file);
+        assert_eq!(config.verbosity.value, 0);
+        assert_eq!(config.verbosity.source, Source::Env("AOC_VERBOSITY"));
+        assert_eq!(config.threads.value, 2);
+        assert_eq!(config.threads.source, Source::CommandLine);
+        assert_eq!(config.vault_key_file.value, "/keys/aoc");
+
+        let params = config.params(2024, 14);
+        assert_eq!(params.get("width"), Some("13"));
+        assert_eq!(params.get("height"), Some("7"));
+        assert_eq!(params.get("threads"), Some("2"));
+        assert!(config.show().contains("height = \"7\"  # aoc.toml"));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let args = ConfigArgs {
+            file: Some("missing.toml".to_string()),
+            overrides: vec![],
+        };
+        assert!(Config::resolve(&args, no_env, config_file).is_err());
+
+        let unknown = |_: &str| Ok("colour = \"blue\"".to_string());
+        assert!(Config::resolve(&ConfigArgs::default(), no_env, unknown).is_err());
+
+        let args = ConfigArgs {
+            file: None,
+            overrides: vec![("threads".to_string(), "0".to_string())],
+        };
+        assert!(Config::resolve(&args, no_env, config_file).is_err());
+    }
+}
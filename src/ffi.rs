@@ -0,0 +1,294 @@
+use std::ffi::{c_char, CStr};
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+use std::sync::OnceLock;
+
+use crate::registry::Registry;
+
+pub const AOC_OK: i32 = 0;
+pub const AOC_ERR_NULL_POINTER: i32 = -1;
+pub const AOC_ERR_INVALID_UTF8: i32 = -2;
+pub const AOC_ERR_UNKNOWN_DAY: i32 = -3;
+pub const AOC_ERR_UNKNOWN_PART: i32 = -4;
+pub const AOC_ERR_PARSE: i32 = -5;
+pub const AOC_ERR_BUFFER_TOO_SMALL: i32 = -6;
+pub const AOC_ERR_PANIC: i32 = -7;
+
+pub const HEADER_PATH: &str = "include/aoc.h";
+
+const ERRORS: [(&str, i32, &CStr); 8] = [
+    ("AOC_OK", AOC_OK, c"ok"),
+    (
+        "AOC_ERR_NULL_POINTER",
+        AOC_ERR_NULL_POINTER,
+        c"null pointer argument",
+    ),
+    (
+        "AOC_ERR_INVALID_UTF8",
+        AOC_ERR_INVALID_UTF8,
+        c"input is not valid UTF-8",
+    ),
+    (
+        "AOC_ERR_UNKNOWN_DAY",
+        AOC_ERR_UNKNOWN_DAY,
+        c"no solution for this day",
+    ),
+    (
+        "AOC_ERR_UNKNOWN_PART",
+        AOC_ERR_UNKNOWN_PART,
+        c"part has to be 1 or 2",
+    ),
+    ("AOC_ERR_PARSE", AOC_ERR_PARSE, c"input could not be parsed"),
+    (
+        "AOC_ERR_BUFFER_TOO_SMALL",
+        AOC_ERR_BUFFER_TOO_SMALL,
+        c"output buffer is too small",
+    ),
+    ("AOC_ERR_PANIC", AOC_ERR_PANIC, c"solver panicked"),
+];
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::new)
+}
+
+fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> Result<String, i32> {
+    let solution = registry().get(year, day).ok_or(AOC_ERR_UNKNOWN_DAY)?;
+    if part != 1 && part != 2 {
+        return Err(AOC_ERR_UNKNOWN_PART);
+    }
+    let input = std::str::from_utf8(input).map_err(|_| AOC_ERR_INVALID_UTF8)?;
+
+    match panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input))) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(_)) => Err(AOC_ERR_PARSE),
+        Err(_) => Err(AOC_ERR_PANIC),
+    }
+}
+
+/// Solves `part` of `day` of `year` and writes the NUL-terminated answer to `out_buf`.
+///
+/// `*out_len` receives the answer length without the NUL byte; with
+/// `AOC_ERR_BUFFER_TOO_SMALL` it receives the capacity needed, including the NUL byte.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes, `out_buf` to `out_cap` writable bytes
+/// and `out_len` to a writable `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_cap: usize,
+    out_len: *mut usize,
+) -> i32 {
+    if (input.is_null() && input_len > 0) || out_buf.is_null() || out_len.is_null() {
+        return AOC_ERR_NULL_POINTER;
+    }
+    let input = match input_len {
+        0 => &[][..],
+        _ => slice::from_raw_parts(input, input_len),
+    };
+
+    let answer = match solve(year, day, part, input) {
+        Ok(answer) => answer,
+        Err(code) => return code,
+    };
+    if answer.len() + 1 > out_cap {
+        *out_len = answer.len() + 1;
+        return AOC_ERR_BUFFER_TOO_SMALL;
+    }
+
+    let out = slice::from_raw_parts_mut(out_buf as *mut u8, out_cap);
+    out[..answer.len()].copy_from_slice(answer.as_bytes());
+    out[answer.len()] = 0;
+    *out_len = answer.len();
+    AOC_OK
+}
+
+/// Same as `aoc_solve_year` for the latest year with solutions.
+///
+/// # Safety
+///
+/// See `aoc_solve_year`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_cap: usize,
+    out_len: *mut usize,
+) -> i32 {
+    let year = match registry().years().last() {
+        Some(year) => *year,
+        None => return AOC_ERR_UNKNOWN_DAY,
+    };
+    aoc_solve_year(year, day, part, input, input_len, out_buf, out_cap, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn aoc_error_message(code: i32) -> *const c_char {
+    let message = match ERRORS.iter().find(|(_, value, _)| *value == code) {
+        Some((_, _, message)) => message,
+        None => c"unknown error code",
+    };
+    message.as_ptr()
+}
+
+// the C declarations of everything above, checked in as include/aoc.h
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* Generated from src/ffi.rs by `UPDATE_HEADER=1 cargo test ffi`, do not edit. */\n\
+         #ifndef AOC_H\n\
+         #define AOC_H\n\n\
+         #include <stddef.h>\n\
+         #include <stdint.h>\n\n",
+    );
+    for (name, value, message) in ERRORS {
+        let message = message.to_str().unwrap();
+        header.push_str(&format!("#define {} {} /* {} */\n", name, value, message));
+    }
+    header.push_str(
+        "\n#ifdef __cplusplus\n\
+         extern \"C\" {\n\
+         #endif\n\n\
+         /* Writes the NUL-terminated answer to out_buf and its length to *out_len.\n   \
+         On AOC_ERR_BUFFER_TOO_SMALL *out_len is the capacity needed. */\n\
+         int32_t aoc_solve_year(uint16_t year, uint8_t day, uint8_t part,\n                       \
+         const uint8_t *input, size_t input_len,\n                       \
+         char *out_buf, size_t out_cap, size_t *out_len);\n\n\
+         /* aoc_solve_year for the latest year. */\n\
+         int32_t aoc_solve(uint8_t day, uint8_t part,\n                  \
+         const uint8_t *input, size_t input_len,\n                  \
+         char *out_buf, size_t out_cap, size_t *out_len);\n\n\
+         /* Static NUL-terminated description of an error code. */\n\
+         const char *aoc_error_message(int32_t code);\n\n\
+         #ifdef __cplusplus\n\
+         }\n\
+         #endif\n\n\
+         #endif /* AOC_H */\n",
+    );
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the exported symbols, resolved by the linker like a C caller would
+    mod abi {
+        use std::ffi::c_char;
+
+        extern "C" {
+            pub fn aoc_solve(
+                day: u8,
+                part: u8,
+                input: *const u8,
+                input_len: usize,
+                out_buf: *mut c_char,
+                out_cap: usize,
+                out_len: *mut usize,
+            ) -> i32;
+            pub fn aoc_error_message(code: i32) -> *const c_char;
+        }
+    }
+
+    const DAY_1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn call(day: u8, part: u8, input: &str, out_cap: usize) -> (i32, String, usize) {
+        let mut out_buf = vec![0 as c_char; out_cap.max(1)];
+        let mut out_len = 0;
+        let code = unsafe {
+            abi::aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out_buf.as_mut_ptr(),
+                out_cap,
+                &mut out_len,
+            )
+        };
+        let answer = match code {
+            AOC_OK => unsafe { CStr::from_ptr(out_buf.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
+            _ => String::new(),
+        };
+        (code, answer, out_len)
+    }
+
+    #[test]
+    fn test_aoc_solve() {
+        assert_eq!(call(1, 1, DAY_1, 64), (AOC_OK, "11".to_string(), 2));
+        assert_eq!(call(1, 2, DAY_1, 3), (AOC_OK, "31".to_string(), 2));
+    }
+
+    #[test]
+    fn test_aoc_solve_errors() {
+        assert_eq!(
+            call(1, 2, DAY_1, 2),
+            (AOC_ERR_BUFFER_TOO_SMALL, String::new(), 3)
+        );
+        assert_eq!(call(26, 1, DAY_1, 64).0, AOC_ERR_UNKNOWN_DAY);
+        assert_eq!(call(1, 3, DAY_1, 64).0, AOC_ERR_UNKNOWN_PART);
+        assert_eq!(call(1, 1, "3 4", 64).0, AOC_ERR_PARSE);
+
+        let invalid = [0xff, 0xfe];
+        let mut out_buf = [0 as c_char; 8];
+        let mut out_len = 0;
+        let code = unsafe {
+            abi::aoc_solve(
+                1,
+                1,
+                invalid.as_ptr(),
+                2,
+                out_buf.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(code, AOC_ERR_INVALID_UTF8);
+        let code = unsafe {
+            abi::aoc_solve(
+                1,
+                1,
+                std::ptr::null(),
+                2,
+                out_buf.as_mut_ptr(),
+                8,
+                &mut out_len,
+            )
+        };
+        assert_eq!(code, AOC_ERR_NULL_POINTER);
+    }
+
+    #[test]
+    fn test_aoc_error_message() {
+        let message = |code| unsafe { CStr::from_ptr(abi::aoc_error_message(code)) };
+        assert_eq!(
+            message(AOC_ERR_PARSE).to_str(),
+            Ok("input could not be parsed")
+        );
+        assert_eq!(message(42).to_str(), Ok("unknown error code"));
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        if std::env::var("UPDATE_HEADER").is_ok() {
+            std::fs::write(HEADER_PATH, header()).unwrap();
+        }
+        let checked_in = std::fs::read_to_string(HEADER_PATH).unwrap_or_default();
+        assert!(
+            checked_in == header(),
+            "{} is out of date, regenerate it with `UPDATE_HEADER=1 cargo test ffi`",
+            HEADER_PATH
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::utils::{self, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    #[serde(default)]
+    pub star_index: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub stars: u32,
+    #[serde(default)]
+    pub local_score: u64,
+    // day -> part -> star, keys are strings in the export
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<String, BTreeMap<String, Star>>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    pub fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level
+            .get(&day.to_string())
+            .and_then(|parts| parts.get(&part.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    #[serde(default)]
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl FromStr for Leaderboard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+            .map_err(|err| ParseError::new(format!("Invalid leaderboard export: {}", err)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimes {
+    pub day: u8,
+    // seconds since the puzzle unlocked
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl DayTimes {
+    pub fn delta(&self) -> Option<i64> {
+        match (self.part_1, self.part_2) {
+            (Some(part_1), Some(part_2)) => Some(part_2 - part_1),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    pub member_id: u64,
+    pub score: u64,
+    pub rank: usize,
+}
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// puzzles unlock at midnight EST (UTC-5)
+pub fn unlock_ts(year: i64, day: u8) -> i64 {
+    days_from_civil(year, 12, day as i64) * 86400 + 5 * 3600
+}
+
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    format!(
+        "{}{:02}:{:02}:{:02}",
+        sign,
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+impl Leaderboard {
+    pub fn year(&self) -> Result<i64, ParseError> {
+        utils::parse_number(&self.event)
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self
+            .members
+            .values()
+            .flat_map(|member| member.completion_day_level.keys())
+            .filter_map(|day| day.parse().ok())
+            .collect();
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    pub fn star_times(&self, member: &Member) -> Result<Vec<DayTimes>, ParseError> {
+        let year = self.year()?;
+        let mut times = Vec::new();
+
+        for day in self.days() {
+            let unlocked = unlock_ts(year, day);
+            let part_1 = member.star(day, 1).map(|star| star.get_star_ts - unlocked);
+            let part_2 = member.star(day, 2).map(|star| star.get_star_ts - unlocked);
+            if part_1.is_some() || part_2.is_some() {
+                times.push(DayTimes {
+                    day,
+                    part_1,
+                    part_2,
+                });
+            }
+        }
+
+        Ok(times)
+    }
+
+    // local score after every day: the first member to get a star gets as many points
+    // as there are members on the leaderboard, the second one point less and so on
+    pub fn daily_standings(&self) -> Vec<(u8, Vec<Standing>)> {
+        let num_of_members = self.members.len() as u64;
+        let mut scores: BTreeMap<u64, u64> = self.members.values().map(|m| (m.id, 0)).collect();
+        let mut standings = Vec::new();
+
+        for day in self.days() {
+            for part in 1..=2 {
+                let mut finishers: Vec<_> = self
+                    .members
+                    .values()
+                    .filter_map(|member| member.star(day, part).map(|star| (star, member.id)))
+                    .collect();
+                finishers.sort_by_key(|(star, id)| (star.get_star_ts, star.star_index, *id));
+
+                for (position, (_, id)) in finishers.iter().enumerate() {
+                    *scores.entry(*id).or_default() += num_of_members - position as u64;
+                }
+            }
+
+            let mut day_standings: Vec<_> = scores
+                .iter()
+                .map(|(member_id, score)| Standing {
+                    member_id: *member_id,
+                    score: *score,
+                    rank: 1 + scores.values().filter(|other| *other > score).count(),
+                })
+                .collect();
+            day_standings.sort_by_key(|standing| (standing.rank, standing.member_id));
+            standings.push((day, day_standings));
+        }
+
+        standings
+    }
+
+    fn members_by_rank(&self) -> Vec<&Member> {
+        let standings = self.daily_standings();
+        let mut members: Vec<_> = self.members.values().collect();
+        if let Some((_, last_day)) = standings.last() {
+            let rank = |id: u64| last_day.iter().position(|s| s.member_id == id);
+            members.sort_by_key(|member| (rank(member.id), member.id));
+        }
+        members
+    }
+}
+
+fn median(values: &mut [i64]) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort();
+    Some(values[values.len() / 2])
+}
+
+fn format_time(time: Option<i64>) -> String {
+    match time {
+        Some(seconds) => format_duration(seconds),
+        None => "-".to_string(),
+    }
+}
+
+fn write_star_times(report: &mut String, leaderboard: &Leaderboard) -> Result<(), ParseError> {
+    writeln!(report, "Star times (since unlock): part 1 / part 2 / delta").unwrap();
+    for member in leaderboard.members_by_rank() {
+        writeln!(report, "\n{}", member.display_name()).unwrap();
+        let times = leaderboard.star_times(member)?;
+        if times.is_empty() {
+            writeln!(report, "  no stars yet").unwrap();
+        }
+        for day_times in times {
+            writeln!(
+                report,
+                "  Day {:>2}: {:>9} / {:>9} / {:>9}",
+                day_times.day,
+                format_time(day_times.part_1),
+                format_time(day_times.part_2),
+                format_time(day_times.delta()),
+            )
+            .unwrap();
+        }
+    }
+    Ok(())
+}
+
+fn write_rank_changes(report: &mut String, leaderboard: &Leaderboard) {
+    let standings = leaderboard.daily_standings();
+
+    writeln!(
+        report,
+        "\nRank after each day (change since previous day)\n"
+    )
+    .unwrap();
+    write!(report, "{:<24}", "Member").unwrap();
+    for (day, _) in &standings {
+        write!(report, " {:>8}", format!("Day {}", day)).unwrap();
+    }
+    writeln!(report).unwrap();
+
+    for member in leaderboard.members_by_rank() {
+        let mut name = member.display_name();
+        name.truncate(24);
+        write!(report, "{:<24}", name).unwrap();
+
+        let mut previous_rank = None;
+        for (_, day_standings) in &standings {
+            let rank = day_standings
+                .iter()
+                .find(|standing| standing.member_id == member.id)
+                .map(|standing| standing.rank)
+                .unwrap_or(day_standings.len());
+            let cell = match previous_rank {
+                None => rank.to_string(),
+                Some(previous) if previous == rank => format!("{} (=)", rank),
+                Some(previous) => format!("{} ({:+})", rank, previous as i64 - rank as i64),
+            };
+            write!(report, " {:>8}", cell).unwrap();
+            previous_rank = Some(rank);
+        }
+        writeln!(report).unwrap();
+    }
+}
+
+fn write_summary(report: &mut String, leaderboard: &Leaderboard) -> Result<(), ParseError> {
+    let total_stars: usize = leaderboard
+        .members
+        .values()
+        .map(|member| {
+            member
+                .completion_day_level
+                .values()
+                .map(|p| p.len())
+                .sum::<usize>()
+        })
+        .sum();
+
+    writeln!(report, "\nTeam summary\n").unwrap();
+    writeln!(
+        report,
+        "Event {}: {} members, {} stars",
+        leaderboard.event,
+        leaderboard.members.len(),
+        total_stars
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "{:>3} {:>6} {:>6}  {:<34} {:<34} {:>12}",
+        "Day", "Part 1", "Part 2", "Fastest part 1", "Fastest part 2", "Median delta"
+    )
+    .unwrap();
+
+    let mut member_times = Vec::new();
+    for member in leaderboard.members.values() {
+        member_times.push((member, leaderboard.star_times(member)?));
+    }
+
+    for day in leaderboard.days() {
+        let day_times: Vec<_> = member_times
+            .iter()
+            .filter_map(|(member, times)| {
+                times
+                    .iter()
+                    .find(|t| t.day == day)
+                    .map(|times| (*member, *times))
+            })
+            .collect();
+
+        let fastest = |part: fn(&DayTimes) -> Option<i64>| {
+            day_times
+                .iter()
+                .filter_map(|(member, times)| part(times).map(|time| (time, member.id, member)))
+                .min_by_key(|(time, id, _)| (*time, *id))
+                .map(|(time, _, member)| {
+                    let mut name = member.display_name();
+                    name.truncate(24);
+                    format!("{} {}", name, format_duration(time))
+                })
+                .unwrap_or("-".to_string())
+        };
+        let mut deltas: Vec<_> = day_times.iter().filter_map(|(_, t)| t.delta()).collect();
+
+        writeln!(
+            report,
+            "{:>3} {:>6} {:>6}  {:<34} {:<34} {:>12}",
+            day,
+            day_times.iter().filter(|(_, t)| t.part_1.is_some()).count(),
+            day_times.iter().filter(|(_, t)| t.part_2.is_some()).count(),
+            fastest(|t| t.part_1),
+            fastest(|t| t.part_2),
+            format_time(median(&mut deltas)),
+        )
+        .unwrap();
+    }
+    Ok(())
+}
+
+pub fn report(leaderboard: &Leaderboard) -> Result<String, ParseError> {
+    let mut report = String::new();
+
+    write_star_times(&mut report, leaderboard)?;
+    write_rank_changes(&mut report, leaderboard);
+    write_summary(&mut report, leaderboard)?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // day 1 of 2024 unlocked at 1733029200, day 2 at 1733115600
+    const EXPORT: &str = r#"{
+        "event": "2024",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 4, "local_score": 11, "global_score": 0,
+                "last_star_ts": 1733116800,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029500, "star_index": 10},
+                        "2": {"get_star_ts": 1733029800, "star_index": 11}
+                    },
+                    "2": {
+                        "1": {"get_star_ts": 1733116200, "star_index": 30},
+                        "2": {"get_star_ts": 1733116800, "star_index": 31}
+                    }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 3, "local_score": 10, "global_score": 0,
+                "last_star_ts": 1733116000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1733029400, "star_index": 9}
+                    },
+                    "2": {
+                        "1": {"get_star_ts": 1733115900, "star_index": 20},
+                        "2": {"get_star_ts": 1733116000, "star_index": 21}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_unlock_ts() {
+        assert_eq!(unlock_ts(2024, 1), 1733029200);
+        assert_eq!(unlock_ts(2024, 2), 1733115600);
+        assert_eq!(unlock_ts(2015, 1), 1448946000);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "00:00:00");
+        assert_eq!(format_duration(3 * 3600 + 61), "03:01:01");
+        assert_eq!(format_duration(30 * 3600), "30:00:00");
+        assert_eq!(format_duration(-90), "-00:01:30");
+    }
+
+    #[test]
+    fn test_star_times() {
+        let leaderboard: Leaderboard = EXPORT.parse().unwrap();
+        let alice = &leaderboard.members["1"];
+
+        let times = leaderboard.star_times(alice).unwrap();
+        assert_eq!(
+            times[0],
+            DayTimes {
+                day: 1,
+                part_1: Some(300),
+                part_2: Some(600),
+            }
+        );
+        assert_eq!(times[0].delta(), Some(300));
+        assert_eq!(times[1].delta(), Some(600));
+
+        let anonymous = &leaderboard.members["2"];
+        assert_eq!(anonymous.display_name(), "(anonymous user #2)");
+        assert_eq!(leaderboard.star_times(anonymous).unwrap()[0].delta(), None);
+    }
+
+    #[test]
+    fn test_daily_standings() {
+        let leaderboard: Leaderboard = EXPORT.parse().unwrap();
+        let standings = leaderboard.daily_standings();
+
+        // day 1: member 2 was first on part 1 (2 + 1 points), only Alice solved part 2
+        let (day, day_1) = &standings[0];
+        assert_eq!(*day, 1);
+        assert_eq!(
+            day_1,
+            &vec![
+                Standing {
+                    member_id: 1,
+                    score: 3,
+                    rank: 1,
+                },
+                Standing {
+                    member_id: 2,
+                    score: 2,
+                    rank: 2,
+                },
+            ]
+        );
+
+        // day 2: member 2 won both parts and took over the lead
+        let (_, day_2) = &standings[1];
+        assert_eq!(day_2[0].member_id, 2);
+        assert_eq!(day_2[0].score, 6);
+        assert_eq!(day_2[1].score, 5);
+    }
+
+    #[test]
+    fn test_report() {
+        let leaderboard: Leaderboard = EXPORT.parse().unwrap();
+        let report = report(&leaderboard).unwrap();
+
+        assert!(report.contains("  Day  1:  00:05:00 /  00:10:00 /  00:05:00"));
+        assert!(report.contains("2 (-1)"));
+        assert!(report.contains("1 (+1)"));
+        assert!(report.contains("Event 2024: 2 members, 7 stars"));
+    }
+
+    #[test]
+    fn test_invalid_export() {
+        assert!("{}".parse::<Leaderboard>().is_err());
+        assert!("not json".parse::<Leaderboard>().is_err());
+    }
+}
@@ -0,0 +1,21 @@
+#![allow(clippy::needless_range_loop)]
+
+pub mod answers;
+pub mod cache;
+pub mod checkpoint;
+pub mod cli;
+pub mod compare;
+pub mod config;
+pub mod ffi;
+pub mod leaderboard;
+pub mod memory;
+pub mod params;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod server;
+pub mod trace;
+pub mod utils;
+pub mod vault;
+
+pub mod y2024;
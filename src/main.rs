@@ -1,9 +1,467 @@
-mod aoc_15;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use aoc::answers::{Answers, AnswersFile, DEFAULT_ANSWERS_FILE};
+use aoc::cache::{Cache, CacheKey};
+use aoc::cli::{self, Command, ReportArgs, ServeArgs, SolveArgs, VaultArgs};
+use aoc::compare;
+use aoc::config::Config;
+use aoc::leaderboard::{self, Leaderboard};
+use aoc::memory;
+use aoc::params;
+use aoc::registry::{Registry, Solution};
+use aoc::repl::Repl;
+use aoc::report::{self, DEFAULT_REPORT_FILE};
+use aoc::server::{self, Limits, Server};
+use aoc::trace;
+use aoc::vault;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+fn load_answers(answers_file: &AnswersFile) -> Answers {
+    match answers_file.load() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Cannot load {}: {}", answers_file.path().display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+fn check_answer(answers: &Answers, solution: &Solution, part: u8, answer: &str) -> String {
+    match answers.check(solution.year, solution.day, part, answer) {
+        Some(true) => " (correct)".to_string(),
+        Some(false) => " (wrong)".to_string(),
+        None => String::new(),
+    }
+}
+
+fn find_solution<'a>(registry: &'a Registry, args: &SolveArgs) -> &'a Solution {
+    let year = match args.year {
+        Some(year) => year,
+        None => *registry.years().last().expect("No solutions registered"),
+    };
+    let day = match args.day {
+        Some(day) => day,
+        None => match registry.days(year).last() {
+            Some(solution) => solution.day,
+            None => {
+                eprintln!("No solutions for year {}", year);
+                process::exit(1);
+            }
+        },
+    };
+    match registry.get(year, day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!("No solution for year {} day {}", year, day);
+            process::exit(1);
+        }
+    }
+}
+
+fn start_trace(args: &SolveArgs) {
+    if args.trace.is_some() {
+        trace::enable();
+    }
+}
+
+fn finish_trace(args: &SolveArgs) {
+    if let Some(path) = &args.trace {
+        match trace::write_chrome_trace(path) {
+            Ok(events) => println!("Wrote {} trace events to {}", events, path),
+            Err(err) => {
+                eprintln!("Cannot write {}: {}", path, err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn read_input(config: &Config, solution: &Solution, args: &SolveArgs) -> (String, String) {
+    let input_path = match &args.input {
+        Some(path) => path.clone(),
+        None => solution.input_path_in(&config.input_dir.value),
+    };
+    if config.verbosity.value >= 1 {
+        println!("Input filepath: {}", input_path);
+    }
+    let key_file = Path::new(&config.vault_key_file.value);
+    match vault::read_input(Path::new(&input_path), key_file) {
+        Ok(input) => (input_path, input),
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", input_path, err);
+            process::exit(1);
+        }
+    }
+}
+
+fn selected_parts(args: &SolveArgs) -> Vec<u8> {
+    match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn solve(config: &Config, args: SolveArgs) {
+    let registry = Registry::new();
+    let solution = find_solution(&registry, &args);
+    let (year, day) = (solution.year, solution.day);
+
+    let (input_path, input) = read_input(config, solution, &args);
+    let params = config.params(year, day);
+    let cache = Cache::new(&config.cache_dir.value);
+    start_trace(&args);
+    if args.alloc_stats && !memory::is_counting() {
+        eprintln!("Allocation stats need a build with `--features alloc-stats`");
+        process::exit(1);
+    }
+    let answers_file = AnswersFile::new(DEFAULT_ANSWERS_FILE);
+    let mut answers = load_answers(&answers_file);
+    // recorded answers only apply to the puzzle input solved with the default params
+    let has_params = config.params.contains_key(&(year, day));
+    let puzzle_input = args.input.is_none() && !has_params;
+    if args.record && !puzzle_input {
+        eprintln!("Only answers for the puzzle input with default params can be recorded");
+        process::exit(1);
+    }
+    if !puzzle_input {
+        answers = Answers::default();
+    }
+
+    if config.verbosity.value >= 1 {
+        println!("{} day {}: {}", year, day, solution.title);
+    }
+    for part in selected_parts(&args) {
+        let key = CacheKey::new(year, day, part, &input, solution.version);
+        // a cached answer would leave nothing to trace or measure, and it is only valid
+        // for the default params
+        let skip_cache = args.no_cache || args.trace.is_some() || args.alloc_stats;
+        let cached = match skip_cache || has_params {
+            true => None,
+            false => cache.get(&key),
+        };
+
+        let answer = match cached {
+            Some(answer) => {
+                let check = check_answer(&answers, solution, part, &answer);
+                println!("Part {}: {} (cached){}", part, answer, check);
+                answer
+            }
+            None => {
+                let start = Instant::now();
+                let (answer, alloc_stats) = memory::measure(|| {
+                    params::with_params(&params, || solution.solve(part, &input))
+                });
+                let elapsed = start.elapsed();
+
+                match answer {
+                    Ok(answer) => {
+                        let check = check_answer(&answers, solution, part, &answer);
+                        println!("Part {}: {}{}", part, answer, check);
+                        if config.verbosity.value >= 2 {
+                            println!("Part {} time: {:.3?}", part, elapsed);
+                        }
+                        if args.alloc_stats {
+                            println!("Part {} memory: {}", part, alloc_stats);
+                        }
+                        if !has_params {
+                            if let Err(err) = cache.put(&key, &answer) {
+                                eprintln!("Cannot cache the answer: {}", err);
+                            }
+                        }
+                        answer
+                    }
+                    Err(err) => {
+                        eprintln!("Cannot solve {}: {}", input_path, err);
+                        process::exit(1);
+                    }
+                }
+            }
+        };
+        if args.record {
+            answers.record(year, day, part, &answer);
+        }
+    }
+
+    if args.record {
+        match answers_file.save(&answers) {
+            Ok(()) => println!("Recorded answers in {}", answers_file.path().display()),
+            Err(err) => {
+                eprintln!("Cannot save {}: {}", answers_file.path().display(), err);
+                process::exit(1);
+            }
+        }
+    }
+    finish_trace(&args);
+}
+
+fn compare_implementations(config: &Config, args: SolveArgs) {
+    let registry = Registry::new();
+    let solution = find_solution(&registry, &args);
+
+    let (_, input) = read_input(config, solution, &args);
+    let params = config.params(solution.year, solution.day);
+
+    println!("{} day {}: {}", solution.year, solution.day, solution.title);
+    start_trace(&args);
+    let mut all_agree = true;
+    for part in selected_parts(&args) {
+        let runs = params::with_params(&params, || compare::run_all(solution, part, &input));
+        print!("{}", compare::report(part, &runs));
+        all_agree &= compare::agree(&runs);
+    }
+    finish_trace(&args);
+
+    if !all_agree {
+        process::exit(1);
+    }
+}
+
+fn explore(config: &Config, args: SolveArgs) {
+    let registry = Registry::new();
+    let solution = find_solution(&registry, &args);
+    let (input_path, _) = read_input(config, solution, &args);
+    let key_file = PathBuf::from(&config.vault_key_file.value);
+    let load = || vault::read_input(Path::new(&input_path), &key_file);
+
+    let mut repl = match Repl::new(solution, config.params(solution.year, solution.day), load) {
+        Ok(repl) => repl,
+        Err(err) => {
+            eprintln!("Cannot load {}: {}", input_path, err);
+            process::exit(1);
+        }
+    };
+    println!(
+        "{} day {}: {}, `help` lists the commands",
+        solution.year, solution.day, solution.title
+    );
+    if let Err(err) = repl.run(std::io::stdin().lock()) {
+        eprintln!("Cannot read commands: {}", err);
+        process::exit(1);
+    }
+}
+
+fn write_report(config: &Config, args: ReportArgs) {
+    let registry = Registry::new();
+    let year = match args.year {
+        Some(year) => year,
+        None => *registry.years().last().expect("No solutions registered"),
+    };
+    let answers = load_answers(&AnswersFile::new(DEFAULT_ANSWERS_FILE));
+
+    let days = report::collect(&registry, year, &answers, config);
+    let output = args
+        .output
+        .unwrap_or_else(|| DEFAULT_REPORT_FILE.to_string());
+    match std::fs::write(&output, report::markdown(year, &days)) {
+        Ok(()) => println!("Report written to {}", output),
+        Err(err) => {
+            eprintln!("Cannot write {}: {}", output, err);
+            process::exit(1);
+        }
+    }
+}
+
+fn serve(config: &Config, args: ServeArgs) {
+    let defaults = Limits::default();
+    let limits = Limits {
+        max_body_bytes: args.max_body_bytes.unwrap_or(defaults.max_body_bytes),
+        timeout: match args.timeout_seconds {
+            Some(seconds) => Duration::from_secs(seconds),
+            None => defaults.timeout,
+        },
+        max_running_solvers: config.threads.value,
+        ..defaults
+    };
+    let addr = args
+        .addr
+        .unwrap_or_else(|| server::DEFAULT_ADDR.to_string());
+
+    let listener = match server::bind_localhost(&addr) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Cannot listen on {}: {}", addr, err);
+            process::exit(1);
+        }
+    };
+    println!("Listening on http://{}", addr);
+
+    let server = Arc::new(Server::new(Registry::new(), config.clone(), limits));
+    if let Err(err) = server.run(listener) {
+        eprintln!("Server stopped: {}", err);
+        process::exit(1);
+    }
+}
+
+fn clear_cache(config: &Config) {
+    let cache = Cache::new(&config.cache_dir.value);
+    match cache.clear() {
+        Ok(removed) => println!("Removed {} cached answers", removed),
+        Err(err) => {
+            eprintln!("Cannot clear {}: {}", cache.dir().display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+fn load_vault_key(config: &Config) -> vault::Key {
+    match vault::Key::load(Path::new(&config.vault_key_file.value)) {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("{}, create one with `aoc vault init`", err);
+            process::exit(1);
+        }
+    }
+}
+
+// inputs are added before there is a solution for the day, so the path is not taken from the registry
+fn vault_input_path(config: &Config, args: &VaultArgs) -> PathBuf {
+    let year = match args.year {
+        Some(year) => year,
+        None => *Registry::new()
+            .years()
+            .last()
+            .expect("No solutions registered"),
+    };
+    Path::new(&config.input_dir.value)
+        .join(year.to_string())
+        .join(format!("input_{:02}", args.day))
+}
+
+fn init_vault(config: &Config) {
+    let key_file = Path::new(&config.vault_key_file.value);
+    if key_file.exists() {
+        eprintln!("{} already exists", key_file.display());
+        process::exit(1);
+    }
+    let key = vault::Key::generate();
+    match key.save(key_file) {
+        Ok(()) => println!("Created vault key {:?} in {}", key, key_file.display()),
+        Err(err) => {
+            eprintln!("Cannot write {}: {}", key_file.display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+fn add_to_vault(config: &Config, args: VaultArgs) {
+    let key = load_vault_key(config);
+    let source = args.path.as_deref().expect("add takes an input path");
+    match vault::add_input(&key, Path::new(source), &vault_input_path(config, &args)) {
+        Ok(path) => println!("Encrypted {} to {}", source, path.display()),
+        Err(err) => {
+            eprintln!("Cannot add {}: {}", source, err);
+            process::exit(1);
+        }
+    }
+}
+
+// the new key only replaces the old one, kept as `.old`, once every input was re-encrypted
+fn rotate_vault(config: &Config) {
+    let key_file = PathBuf::from(&config.vault_key_file.value);
+    let old_key = load_vault_key(config);
+    let new_key = vault::Key::generate();
+    let new_key_file = PathBuf::from(format!("{}.new", config.vault_key_file.value));
+    let old_key_file = PathBuf::from(format!("{}.old", config.vault_key_file.value));
+
+    let rotated = new_key
+        .save(&new_key_file)
+        .and_then(|()| vault::rotate(&old_key, &new_key, Path::new(&config.input_dir.value)))
+        .and_then(|rotated| {
+            std::fs::rename(&key_file, &old_key_file)?;
+            std::fs::rename(&new_key_file, &key_file)?;
+            Ok(rotated)
+        });
+    match rotated {
+        Ok(rotated) => println!(
+            "Re-encrypted {} inputs with key {:?}, the previous key is in {}",
+            rotated,
+            new_key,
+            old_key_file.display()
+        ),
+        Err(err) => {
+            eprintln!("Cannot rotate the vault key: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn export_from_vault(config: &Config, args: VaultArgs) {
+    let input_path = vault_input_path(config, &args);
+    let key_file = Path::new(&config.vault_key_file.value);
+    let input = match vault::read_input(&input_path, key_file) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", input_path.display(), err);
+            process::exit(1);
+        }
+    };
+    match &args.path {
+        Some(path) => {
+            if let Err(err) = std::fs::write(path, input) {
+                eprintln!("Cannot write {}: {}", path, err);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", input),
+    }
+}
+
+fn show_leaderboard(path: &str) {
+    let export = match std::fs::read_to_string(path) {
+        Ok(export) => export,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", path, err);
+            process::exit(1);
+        }
+    };
+    let report = export
+        .parse::<Leaderboard>()
+        .and_then(|leaderboard| leaderboard::report(&leaderboard));
+    match report {
+        Ok(report) => print!("{}", report),
+        Err(err) => {
+            eprintln!("Cannot parse {}: {}", path, err);
+            process::exit(1);
+        }
+    }
+}
 
 fn main() {
-    let res = aoc_15::solve_part_1("input_15");
-    println!("Result: {}", res);
+    let (config_args, command) = match cli::split_config_args(std::env::args().skip(1))
+        .and_then(|(config_args, rest)| Ok((config_args, cli::parse_args(rest)?)))
+    {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("{}\n{}", err, cli::USAGE);
+            process::exit(2);
+        }
+    };
+    let config = match Config::load(&config_args) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Cannot load the configuration: {}", err);
+            process::exit(2);
+        }
+    };
 
-    let res = aoc_15::solve_part_2("input_15");
-    println!("Result: {}", res);
+    match command {
+        Command::Solve(args) => solve(&config, args),
+        Command::Compare(args) => compare_implementations(&config, args),
+        Command::Repl(args) => explore(&config, args),
+        Command::Report(args) => write_report(&config, args),
+        Command::Serve(args) => serve(&config, args),
+        Command::CacheClear => clear_cache(&config),
+        Command::ConfigShow => print!("{}", config.show()),
+        Command::Leaderboard(path) => show_leaderboard(&path),
+        Command::VaultInit => init_vault(&config),
+        Command::VaultAdd(args) => add_to_vault(&config, args),
+        Command::VaultRotate => rotate_vault(&config),
+        Command::VaultExport(args) => export_from_vault(&config, args),
+    }
 }
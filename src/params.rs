@@ -0,0 +1,86 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+// per-day overrides of values the solvers would otherwise hardcode, e.g. the d14 map size
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|value| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Params> = RefCell::new(Params::new());
+}
+
+// runs `f` with `params` visible to `get` on this thread, restoring the previous ones afterwards
+pub fn with_params<T>(params: &Params, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.with(|current| current.replace(params.clone()));
+    let result = f();
+    CURRENT.with(|current| current.replace(previous));
+    result
+}
+
+pub fn current() -> Params {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+pub fn get<T>(name: &str, default: T) -> T
+where
+    T: FromStr,
+{
+    CURRENT.with(|current| match current.borrow().get(name) {
+        Some(value) => match value.trim().parse() {
+            Ok(value) => value,
+            Err(_) => panic!("Invalid value for parameter {}: {:?}", name, value),
+        },
+        None => default,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_params() {
+        let mut params = Params::new();
+        params.set("width", "11");
+
+        assert_eq!(get("width", 101), 101);
+        assert_eq!(with_params(&params, || get("width", 101)), 11);
+        assert_eq!(with_params(&params, || get("height", 103)), 103);
+        assert_eq!(get("width", 101), 101);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for parameter width")]
+    fn test_invalid_param() {
+        let mut params = Params::new();
+        params.set("width", "wide");
+        with_params(&params, || get("width", 101));
+    }
+}
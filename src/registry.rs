@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::config::DEFAULT_INPUT_DIR;
+use crate::repl::ExplorerFactory;
+use crate::trace;
+use crate::utils::ParseError;
+use crate::y2024;
+
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+pub type Profiler = fn(&str) -> Result<Profile, ParseError>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub parse: Duration,
+    pub answers: [String; 2],
+    pub parts: [Duration; 2],
+}
+
+pub fn parse_input<T>(input: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    let _span = trace::span("parse");
+    input.parse()
+}
+
+// what a part returns: an answer, or a `Result` whose error is reported like a bad input
+pub trait Answer {
+    fn into_answer(self) -> Result<String, ParseError>;
+}
+
+macro_rules! plain_answer {
+    ($($answer:ty),*) => {
+        $(impl Answer for $answer {
+            fn into_answer(self) -> Result<String, ParseError> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+plain_answer!(u64, usize, i64, String);
+
+impl<A: Answer, E: fmt::Display> Answer for Result<A, E> {
+    fn into_answer(self) -> Result<String, ParseError> {
+        self.map_err(|err| ParseError::new(err.to_string()))?
+            .into_answer()
+    }
+}
+
+pub fn run_part<T, A>(
+    input: &str,
+    name: &'static str,
+    part: impl Fn(&T) -> A,
+) -> Result<String, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+    A: Answer,
+{
+    let input = parse_input(input)?;
+    let _span = trace::span(name);
+    part(&input).into_answer()
+}
+
+// parses the input once and times the parsing and both parts separately
+pub fn profile<T, A, B>(
+    input: &str,
+    part_1: impl Fn(&T) -> A,
+    part_2: impl Fn(&T) -> B,
+) -> Result<Profile, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+    A: Answer,
+    B: Answer,
+{
+    let start = Instant::now();
+    let input: T = parse_input(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer_1 = {
+        let _span = trace::span("part_1");
+        part_1(&input).into_answer()?
+    };
+    let part_1_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let answer_2 = {
+        let _span = trace::span("part_2");
+        part_2(&input).into_answer()?
+    };
+    let part_2_elapsed = start.elapsed();
+
+    Ok(Profile {
+        parse,
+        answers: [answer_1, answer_2],
+        parts: [part_1_elapsed, part_2_elapsed],
+    })
+}
+
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+pub struct Alternative {
+    pub part: u8,
+    pub name: &'static str,
+    pub solver: Solver,
+}
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub version: &'static str,
+    pub source: &'static str,
+    pub parts: [Solver; 2],
+    pub alternatives: Vec<Alternative>,
+    pub profile: Profiler,
+    pub explore: ExplorerFactory,
+}
+
+impl Solution {
+    pub fn input_path(&self) -> String {
+        self.input_path_in(DEFAULT_INPUT_DIR)
+    }
+
+    pub fn input_path_in(&self, input_dir: &str) -> String {
+        format!("{}/{}/input_{:02}", input_dir, self.year, self.day)
+    }
+
+    pub fn solve(&self, part: u8, input: &str) -> Result<String, ParseError> {
+        match part {
+            1 | 2 => (self.parts[part as usize - 1])(input),
+            _ => Err(ParseError::new(format!("Unknown part: {}", part))),
+        }
+    }
+
+    // the default solver first, followed by the alternatives registered for the part
+    pub fn implementations(&self, part: u8) -> Vec<(&'static str, Solver)> {
+        let mut implementations = Vec::new();
+        if part == 1 || part == 2 {
+            implementations.push((DEFAULT_IMPLEMENTATION, self.parts[part as usize - 1]));
+        }
+        for alternative in &self.alternatives {
+            if alternative.part == part {
+                implementations.push((alternative.name, alternative.solver));
+            }
+        }
+        implementations
+    }
+}
+
+// builds a `Solution` out of a day module exposing `Input`, `VERSION`, `part_1` and `part_2`,
+// optionally followed by `; repl: function` for a day specific explorer and by alternatives
+// as `part => "name": function`
+macro_rules! solution {
+    ($year:literal, $day:literal, $module:ident, $title:literal $(; repl: $explore:ident)?
+        $(, $part:literal => $name:literal: $function:ident)* $(,)?) => {
+        $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            title: $title,
+            version: $module::VERSION,
+            source: concat!("src/y", $year, "/", stringify!($module), ".rs"),
+            parts: [
+                |input| $crate::registry::run_part(input, "part_1", $module::part_1),
+                |input| $crate::registry::run_part(input, "part_2", $module::part_2),
+            ],
+            alternatives: vec![$($crate::registry::Alternative {
+                part: $part,
+                name: $name,
+                solver: |input| $crate::registry::run_part(input, $name, $module::$function),
+            }),*],
+            profile: |input| $crate::registry::profile(input, $module::part_1, $module::part_2),
+            explore: $crate::registry::explorer!($module $(, $explore)?),
+        }
+    };
+}
+pub(crate) use solution;
+
+macro_rules! explorer {
+    ($module:ident) => {
+        $crate::repl::explore_input::<$module::Input>
+    };
+    ($module:ident, $explore:ident) => {
+        $module::$explore
+    };
+}
+pub(crate) use explorer;
+
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Solution>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Registry::with_solutions(y2024::solutions())
+    }
+
+    pub fn with_solutions(solutions: Vec<Solution>) -> Self {
+        let solutions = solutions
+            .into_iter()
+            .map(|solution| ((solution.year, solution.day), solution))
+            .collect();
+        Registry { solutions }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Solution> {
+        self.solutions.get(&(year, day))
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<_> = self.solutions.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: u16) -> impl Iterator<Item = &Solution> {
+        self.solutions
+            .range((year, 0)..=(year, u8::MAX))
+            .map(|(_, solution)| solution)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.solutions.values()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_year_and_day() {
+        let registry = Registry::new();
+        let solution = registry.get(2024, 15).unwrap();
+
+        assert_eq!(solution.title, "Warehouse Woes");
+        assert_eq!(solution.input_path(), "inputs/2024/input_15");
+        assert_eq!(solution.source, "src/y2024/d15.rs");
+        assert_eq!(solution.input_path_in("/data"), "/data/2024/input_15");
+        assert!(registry.get(2024, 26).is_none());
+        assert!(registry.get(2015, 1).is_none());
+    }
+
+    #[test]
+    fn test_days_are_ordered() {
+        let registry = Registry::new();
+        let days: Vec<_> = registry.days(2024).map(|s| s.day).collect();
+
+        assert_eq!(days, (1..=15).collect::<Vec<_>>());
+        assert_eq!(registry.years(), vec![2024]);
+    }
+
+    #[test]
+    fn test_solve_from_text() {
+        let registry = Registry::new();
+        let solution = registry.get(2024, 1).unwrap();
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        assert_eq!(solution.solve(1, input), Ok("11".to_string()));
+        assert_eq!(solution.solve(2, input), Ok("31".to_string()));
+        assert!(solution.solve(3, input).is_err());
+        assert!(solution.solve(1, "3 4").is_err());
+
+        let profile = (solution.profile)(input).unwrap();
+        assert_eq!(profile.answers, ["11".to_string(), "31".to_string()]);
+        assert!((solution.profile)("3 4").is_err());
+    }
+
+    #[test]
+    fn test_implementations() {
+        let registry = Registry::new();
+        let solution = registry.get(2024, 10).unwrap();
+        let names: Vec<_> = solution
+            .implementations(2)
+            .iter()
+            .map(|(name, _)| *name)
+            .collect();
+
+        assert_eq!(names, vec![DEFAULT_IMPLEMENTATION, "layered"]);
+        assert!(solution.implementations(3).is_empty());
+        assert_eq!(registry.get(2024, 1).unwrap().implementations(1).len(), 1);
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+
+use crate::params::{self, Params};
+use crate::registry::{self, Solution};
+use crate::utils::{self, ParseError};
+
+pub const HELP: &str = "Commands:
+    part1, part2      solve a part of the loaded input
+    show              render the current state
+    step N            advance a simulation by N steps (1 without N)
+    set NAME=VALUE    set a day parameter, `set` alone lists them, `reload` to apply
+                      it to a simulation
+    save PATH         write the simulation state to a checkpoint file
+    load PATH         resume the simulation from a checkpoint file
+    reload            read and parse the input again, resetting the state
+    help
+    quit";
+
+// the parsed input of a day, kept across commands
+pub trait Explorer {
+    fn show(&self);
+
+    fn step(&mut self, _steps: usize) -> Result<String, String> {
+        Err("This day has no simulation to step through".to_string())
+    }
+
+    fn checkpoint(&self) -> Result<String, String> {
+        Err("This day has no simulation state to save".to_string())
+    }
+
+    fn restore(&mut self, _checkpoint: &str) -> Result<String, String> {
+        Err("This day has no simulation state to load".to_string())
+    }
+}
+
+pub type ExplorerFactory = fn(&str) -> Result<Box<dyn Explorer>, ParseError>;
+
+// what every day supports: showing the input as it was parsed
+pub struct InputExplorer<T> {
+    input: T,
+}
+
+impl<T: fmt::Display> Explorer for InputExplorer<T> {
+    fn show(&self) {
+        print!("{}", self.input);
+    }
+}
+
+pub fn explore_input<T>(input: &str) -> Result<Box<dyn Explorer>, ParseError>
+where
+    T: FromStr<Err = ParseError> + fmt::Display + 'static,
+{
+    let input: T = registry::parse_input(input)?;
+    Ok(Box::new(InputExplorer { input }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Part(u8),
+    Show,
+    Step(usize),
+    Set(Option<(String, String)>),
+    Save(String),
+    Load(String),
+    Reload,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, argument) = match s.trim().split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, Some(argument.trim())),
+            None => (s.trim(), None),
+        };
+        let command = match (name, argument) {
+            ("part1", None) => Command::Part(1),
+            ("part2", None) => Command::Part(2),
+            ("show", None) => Command::Show,
+            ("step", None) => Command::Step(1),
+            ("step", Some(steps)) => Command::Step(utils::parse_number(steps)?),
+            ("set", None) => Command::Set(None),
+            ("set", Some(setting)) => match setting.split_once('=') {
+                Some((name, value)) => {
+                    Command::Set(Some((name.trim().to_string(), value.trim().to_string())))
+                }
+                None => return Err(ParseError::new("Expected set NAME=VALUE")),
+            },
+            ("save", Some(path)) => Command::Save(path.to_string()),
+            ("load", Some(path)) => Command::Load(path.to_string()),
+            ("reload", None) => Command::Reload,
+            ("help", None) => Command::Help,
+            ("quit" | "exit", None) => Command::Quit,
+            _ => return Err(ParseError::new(format!("Unknown command: {:?}", s.trim()))),
+        };
+        Ok(command)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    Output(String),
+    Done,
+    Quit,
+}
+
+pub struct Repl<'a> {
+    solution: &'a Solution,
+    load: Box<dyn Fn() -> io::Result<String> + 'a>,
+    input: String,
+    params: Params,
+    explorer: Box<dyn Explorer>,
+}
+
+// solvers and simulations may panic on bad params, which should not end the session
+fn catch<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => Err(match payload.downcast_ref::<String>() {
+            Some(message) => format!("Panicked: {}", message),
+            None => match payload.downcast_ref::<&str>() {
+                Some(message) => format!("Panicked: {}", message),
+                None => "Panicked".to_string(),
+            },
+        }),
+    }
+}
+
+impl<'a> Repl<'a> {
+    // `load` reads the input, it is called again on `reload`
+    pub fn new(
+        solution: &'a Solution,
+        params: Params,
+        load: impl Fn() -> io::Result<String> + 'a,
+    ) -> Result<Self, String> {
+        let input = load().map_err(|err| err.to_string())?;
+        let explorer = params::with_params(&params, || (solution.explore)(&input))
+            .map_err(|err| err.to_string())?;
+        Ok(Repl {
+            solution,
+            load: Box::new(load),
+            input,
+            params,
+            explorer,
+        })
+    }
+
+    pub fn prompt(&self) -> String {
+        format!("{}/{:02}> ", self.solution.year, self.solution.day)
+    }
+
+    pub fn execute(&mut self, line: &str) -> Result<Reply, String> {
+        if line.trim().is_empty() {
+            return Ok(Reply::Done);
+        }
+        let command: Command = line.parse().map_err(|err: ParseError| err.to_string())?;
+        let params = self.params.clone();
+
+        match command {
+            Command::Part(part) => {
+                let (solution, input) = (self.solution, &self.input);
+                let answer = catch(|| {
+                    params::with_params(&params, || solution.solve(part, input))
+                        .map_err(|err| err.to_string())
+                })?;
+                Ok(Reply::Output(format!("Part {}: {}", part, answer)))
+            }
+            Command::Show => {
+                let explorer = &self.explorer;
+                catch(|| {
+                    params::with_params(&params, || explorer.show());
+                    Ok(())
+                })?;
+                Ok(Reply::Done)
+            }
+            Command::Step(steps) => {
+                let explorer = &mut self.explorer;
+                let output = catch(|| params::with_params(&params, || explorer.step(steps)))?;
+                Ok(Reply::Output(output))
+            }
+            Command::Set(None) => {
+                let lines: Vec<_> = self
+                    .params
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                Ok(Reply::Output(lines.join("\n")))
+            }
+            Command::Set(Some((name, value))) => {
+                // the parts read params when they run, but the explorer read them when it was
+                // built, so a simulation keeps the old values until it is rebuilt
+                let output = format!(
+                    "{} = {}, `reload` to restart the simulation with it",
+                    name, value
+                );
+                self.params.set(name, value);
+                Ok(Reply::Output(output))
+            }
+            Command::Save(path) => {
+                let checkpoint = self.explorer.checkpoint()?;
+                fs::write(&path, checkpoint + "\n")
+                    .map_err(|err| format!("Cannot write {}: {}", path, err))?;
+                Ok(Reply::Output(format!("Saved to {}", path)))
+            }
+            Command::Load(path) => {
+                let checkpoint = fs::read_to_string(&path)
+                    .map_err(|err| format!("Cannot read {}: {}", path, err))?;
+                Ok(Reply::Output(self.explorer.restore(&checkpoint)?))
+            }
+            Command::Reload => {
+                let input = (self.load)().map_err(|err| err.to_string())?;
+                let explorer = params::with_params(&params, || (self.solution.explore)(&input))
+                    .map_err(|err| err.to_string())?;
+                self.input = input;
+                self.explorer = explorer;
+                Ok(Reply::Output("Reloaded".to_string()))
+            }
+            Command::Help => Ok(Reply::Output(HELP.to_string())),
+            Command::Quit => Ok(Reply::Quit),
+        }
+    }
+
+    // reads commands until `quit` or the end of `reader`
+    pub fn run(&mut self, reader: impl BufRead) -> io::Result<()> {
+        let mut lines = reader.lines();
+        loop {
+            print!("{}", self.prompt());
+            io::stdout().flush()?;
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => {
+                    println!();
+                    return Ok(());
+                }
+            };
+            match self.execute(&line) {
+                Ok(Reply::Output(output)) => println!("{}", output),
+                Ok(Reply::Done) => (),
+                Ok(Reply::Quit) => return Ok(()),
+                Err(err) => eprintln!("{}", err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Registry;
+
+    const DAY_1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!("part1".parse(), Ok(Command::Part(1)));
+        assert_eq!(" step 10 ".parse(), Ok(Command::Step(10)));
+        assert_eq!("step".parse(), Ok(Command::Step(1)));
+        assert_eq!(
+            "set width = 11".parse(),
+            Ok(Command::Set(Some(("width".to_string(), "11".to_string()))))
+        );
+        assert_eq!(
+            "save a.json".parse(),
+            Ok(Command::Save("a.json".to_string()))
+        );
+        assert!("load".parse::<Command>().is_err());
+        assert!("set width".parse::<Command>().is_err());
+        assert!("step ten".parse::<Command>().is_err());
+        assert!("part3".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_execute() {
+        let registry = Registry::new();
+        let solution = registry.get(2024, 1).unwrap();
+        let mut repl = Repl::new(solution, Params::new(), || Ok(DAY_1.to_string())).unwrap();
+
+        assert_eq!(
+            repl.execute("part2"),
+            Ok(Reply::Output("Part 2: 31".to_string()))
+        );
+        assert!(repl.execute("step 3").is_err());
+        assert!(repl.execute("save state.json").is_err());
+        assert_eq!(
+            repl.execute("set width=3"),
+            Ok(Reply::Output(
+                "width = 3, `reload` to restart the simulation with it".to_string()
+            ))
+        );
+        assert_eq!(
+            repl.execute("set"),
+            Ok(Reply::Output("width = 3".to_string()))
+        );
+        assert_eq!(
+            repl.execute("reload"),
+            Ok(Reply::Output("Reloaded".to_string()))
+        );
+        assert_eq!(repl.execute(""), Ok(Reply::Done));
+        assert_eq!(repl.execute("quit"), Ok(Reply::Quit));
+        assert!(Repl::new(solution, Params::new(), || Ok("3 4".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_set_needs_reload() {
+        let registry = Registry::new();
+        let solution = registry.get(2024, 14).unwrap();
+        let mut params = Params::new();
+        params.set("width", "11");
+        params.set("height", "7");
+        let load = || fs::read_to_string("inputs/2024/input_14_test");
+        let mut repl = Repl::new(solution, params, load).unwrap();
+        let width = |repl: &Repl| {
+            repl.explorer
+                .checkpoint()
+                .unwrap()
+                .contains("\"map_width\":13")
+        };
+
+        assert!(repl.execute("set width=13").is_ok());
+        assert!(!width(&repl));
+        repl.execute("reload").unwrap();
+        assert!(width(&repl));
+    }
+}
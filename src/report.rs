@@ -0,0 +1,163 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answers::Answers;
+use crate::config::Config;
+use crate::params;
+use crate::registry::{Profile, Registry, Solution};
+use crate::vault;
+
+pub const DEFAULT_REPORT_FILE: &str = "PROGRESS.md";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    pub stars: usize,
+    pub profile: Option<Profile>,
+    pub lines_of_code: Option<usize>,
+    pub source: &'static str,
+}
+
+// blank lines and comment lines are not counted
+pub fn count_lines_of_code(source: &str) -> usize {
+    source
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with("//"))
+        .count()
+}
+
+fn profile_solution(solution: &Solution, config: &Config) -> Option<Profile> {
+    let input_path = solution.input_path_in(&config.input_dir.value);
+    let key_file = Path::new(&config.vault_key_file.value);
+    let input = vault::read_input(Path::new(&input_path), key_file).ok()?;
+    let params = config.params(solution.year, solution.day);
+    params::with_params(&params, || (solution.profile)(&input)).ok()
+}
+
+pub fn collect(
+    registry: &Registry,
+    year: u16,
+    answers: &Answers,
+    config: &Config,
+) -> Vec<DayReport> {
+    registry
+        .days(year)
+        .map(|solution| DayReport {
+            day: solution.day,
+            title: solution.title,
+            stars: answers.stars(year, solution.day),
+            profile: profile_solution(solution, config),
+            lines_of_code: fs::read_to_string(solution.source)
+                .ok()
+                .map(|source| count_lines_of_code(&source)),
+            source: solution.source,
+        })
+        .collect()
+}
+
+// fixed unit and precision so regenerated tables only differ by the measured values
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_stars(stars: usize) -> String {
+    format!("{}{}", "★".repeat(stars), "☆".repeat(2 - stars.min(2)))
+}
+
+pub fn markdown(year: u16, days: &[DayReport]) -> String {
+    let mut report = String::new();
+    let stars: usize = days.iter().map(|day| day.stars).sum();
+
+    writeln!(report, "# Advent of Code {}\n", year).unwrap();
+    writeln!(report, "Stars: {}/{}\n", stars, 2 * days.len()).unwrap();
+    writeln!(
+        report,
+        "| Day | Title | Stars | Parse | Part 1 | Part 2 | LOC | Source |"
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "|----:|-------|:-----:|------:|-------:|-------:|----:|--------|"
+    )
+    .unwrap();
+
+    for day in days {
+        let timings = match &day.profile {
+            Some(profile) => [profile.parse, profile.parts[0], profile.parts[1]]
+                .map(format_duration)
+                .join(" | "),
+            None => "- | - | -".to_string(),
+        };
+        let lines_of_code = match day.lines_of_code {
+            Some(lines) => lines.to_string(),
+            None => "-".to_string(),
+        };
+        let file_name = day.source.rsplit('/').next().unwrap_or(day.source);
+        writeln!(
+            report,
+            "| {} | {} | {} | {} | {} | [{}]({}) |",
+            day.day,
+            day.title,
+            format_stars(day.stars),
+            timings,
+            lines_of_code,
+            file_name,
+            day.source
+        )
+        .unwrap();
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_lines_of_code() {
+        assert_eq!(
+            count_lines_of_code("use std::fmt;\n\n// comment\nfn main() {}\n"),
+            2
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let days = vec![
+            DayReport {
+                day: 1,
+                title: "Historian Hysteria",
+                stars: 2,
+                profile: Some(Profile {
+                    parse: Duration::from_micros(1500),
+                    answers: ["11".to_string(), "31".to_string()],
+                    parts: [Duration::from_micros(20), Duration::from_millis(3)],
+                }),
+                lines_of_code: Some(70),
+                source: "src/y2024/d01.rs",
+            },
+            DayReport {
+                day: 2,
+                title: "Red-Nosed Reports",
+                stars: 1,
+                profile: None,
+                lines_of_code: None,
+                source: "src/y2024/d02.rs",
+            },
+        ];
+
+        assert_eq!(
+            markdown(2024, &days),
+            "# Advent of Code 2024\n\nStars: 3/4\n\n\
+             | Day | Title | Stars | Parse | Part 1 | Part 2 | LOC | Source |\n\
+             |----:|-------|:-----:|------:|-------:|-------:|----:|--------|\n\
+             | 1 | Historian Hysteria | ★★ | 1.500 ms | 0.020 ms | 3.000 ms | 70 | [d01.rs](src/y2024/d01.rs) |\n\
+             | 2 | Red-Nosed Reports | ★☆ | - | - | - | - | [d02.rs](src/y2024/d02.rs) |\n"
+        );
+    }
+}
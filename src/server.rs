@@ -0,0 +1,582 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::params;
+use crate::registry::Registry;
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:8024";
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1 << 20;
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub max_body_bytes: usize,
+    pub timeout: Duration,
+    pub max_running_solvers: usize,
+    // connections answered at once, as many more wait for them and the rest are turned away
+    pub workers: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+            timeout: DEFAULT_TIMEOUT,
+            max_running_solvers: 4,
+            workers: 8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct SolveBody {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    time_ms: Option<f64>,
+    error: Option<String>,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        Response {
+            status,
+            body: serde_json::to_string(body).unwrap(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Response::json(status, &ErrorBody { error: message })
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            413 => "Content Too Large",
+            422 => "Unprocessable Content",
+            431 => "Request Header Fields Too Large",
+            500 => "Internal Server Error",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            _ => "Unknown",
+        }
+    }
+
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(
+            writer,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )?;
+        writer.flush()
+    }
+}
+
+// A stream that has to be read before the deadline, however the client splits up what it sends.
+// A timeout on every read alone lets a client trickling in bytes hold on to a worker for good.
+struct Deadline {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "Request took too long to arrive",
+            ));
+        }
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+fn read_error(err: io::Error) -> Response {
+    match err.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => {
+            Response::error(408, "Timed out reading the request")
+        }
+        _ => Response::error(400, &format!("Cannot read the request: {}", err)),
+    }
+}
+
+// reads a single HTTP/1.1 request, rejecting bodies above `max_body_bytes` before reading them
+pub fn read_request(reader: &mut impl BufRead, max_body_bytes: usize) -> Result<Request, Response> {
+    let mut header_bytes = 0;
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        let read = reader
+            .by_ref()
+            .take((MAX_HEADER_BYTES + 1 - header_bytes) as u64)
+            .read_line(&mut line)
+            .map_err(read_error)?;
+        header_bytes += read;
+        if header_bytes > MAX_HEADER_BYTES {
+            return Err(Response::error(431, "Request headers are too large"));
+        }
+        if read == 0 {
+            return Err(Response::error(400, "Incomplete request"));
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line.to_string());
+    }
+
+    let request_line: Vec<_> = lines
+        .first()
+        .map(|line| line.split_whitespace().collect())
+        .unwrap_or_default();
+    if request_line.len() != 3 || !request_line[2].starts_with("HTTP/1.") {
+        return Err(Response::error(400, "Invalid request line"));
+    }
+
+    let mut content_length = 0;
+    for header in &lines[1..] {
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = match value.trim().parse::<usize>() {
+                    Ok(length) => length,
+                    Err(_) => return Err(Response::error(400, "Invalid Content-Length")),
+                };
+            }
+        }
+    }
+    if content_length > max_body_bytes {
+        let message = format!("Request body is limited to {} bytes", max_body_bytes);
+        return Err(Response::error(413, &message));
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).map_err(read_error)?;
+    let body = match String::from_utf8(body) {
+        Ok(body) => body,
+        Err(_) => return Err(Response::error(400, "Request body is not valid UTF-8")),
+    };
+
+    Ok(Request {
+        method: request_line[0].to_string(),
+        path: request_line[1].to_string(),
+        body,
+    })
+}
+
+// decrements the number of running solvers once the solver thread finishes, even after a timeout
+struct RunningSolver(Arc<AtomicUsize>);
+
+impl Drop for RunningSolver {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+pub struct Server {
+    registry: Arc<Registry>,
+    config: Config,
+    limits: Limits,
+    running_solvers: Arc<AtomicUsize>,
+}
+
+impl Server {
+    pub fn new(registry: Registry, config: Config, limits: Limits) -> Self {
+        Server {
+            registry: Arc::new(registry),
+            config,
+            limits,
+            running_solvers: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    // `/solve/{day}/{part}` for the latest year or `/solve/{year}/{day}/{part}`
+    fn parse_solve_path(&self, path: &str) -> Option<(u16, u8, u8)> {
+        let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+        match segments[..] {
+            ["solve", day, part] => {
+                let year = *self.registry.years().last()?;
+                Some((year, day.parse().ok()?, part.parse().ok()?))
+            }
+            ["solve", year, day, part] => {
+                Some((year.parse().ok()?, day.parse().ok()?, part.parse().ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn handle(&self, request: &Request) -> Response {
+        let (year, day, part) = match self.parse_solve_path(&request.path) {
+            Some(route) => route,
+            None => return Response::error(404, "Expected POST /solve/{day}/{part}"),
+        };
+        if request.method != "POST" {
+            return Response::error(405, "Only POST is supported");
+        }
+        if self.registry.get(year, day).is_none() {
+            let message = format!("No solution for year {} day {}", year, day);
+            return Response::error(404, &message);
+        }
+        if part != 1 && part != 2 {
+            return Response::error(404, &format!("Part has to be 1 or 2, got {}", part));
+        }
+
+        let running = self.running_solvers.fetch_add(1, Ordering::SeqCst);
+        let guard = RunningSolver(Arc::clone(&self.running_solvers));
+        if running >= self.limits.max_running_solvers {
+            return Response::error(503, "Too many solvers running, try again later");
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let registry = Arc::clone(&self.registry);
+        let params = self.config.params(year, day);
+        let input = request.body.clone();
+        thread::spawn(move || {
+            let _guard = guard;
+            let solution = registry.get(year, day).unwrap();
+            let start = Instant::now();
+            let answer = params::with_params(&params, || solution.solve(part, &input));
+            let _ = sender.send((answer, start.elapsed()));
+        });
+
+        let mut body = SolveBody {
+            year,
+            day,
+            part,
+            answer: None,
+            time_ms: None,
+            error: None,
+        };
+        // a solver cannot be interrupted, after a timeout it keeps running until it finishes
+        let status = match receiver.recv_timeout(self.limits.timeout) {
+            Ok((Ok(answer), elapsed)) => {
+                body.answer = Some(answer);
+                body.time_ms = Some(elapsed.as_secs_f64() * 1000.0);
+                200
+            }
+            Ok((Err(err), elapsed)) => {
+                body.error = Some(err.to_string());
+                body.time_ms = Some(elapsed.as_secs_f64() * 1000.0);
+                422
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                body.error = Some(format!("Timed out after {:?}", self.limits.timeout));
+                504
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                body.error = Some("Solver panicked".to_string());
+                500
+            }
+        };
+        Response::json(status, &body)
+    }
+
+    fn handle_connection(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_write_timeout(Some(self.limits.timeout))?;
+        let mut reader = BufReader::new(Deadline {
+            stream: stream.try_clone()?,
+            deadline: Instant::now() + self.limits.timeout,
+        });
+        let response = match read_request(&mut reader, self.limits.max_body_bytes) {
+            Ok(request) => self.handle(&request),
+            Err(response) => response,
+        };
+        let mut stream = stream;
+        response.write_to(&mut stream)
+    }
+
+    // `workers` threads answer the connections, which wait in a queue of as many while they are
+    // busy, and the ones arriving at a full queue are turned away
+    pub fn run(self: Arc<Self>, listener: TcpListener) -> io::Result<()> {
+        let (sender, receiver) = mpsc::sync_channel::<TcpStream>(self.limits.workers);
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..self.limits.workers {
+            let server = Arc::clone(&self);
+            let receiver = Arc::clone(&receiver);
+            thread::spawn(move || loop {
+                let stream = match receiver.lock().unwrap().recv() {
+                    Ok(stream) => stream,
+                    Err(_) => return,
+                };
+                if let Err(err) = server.handle_connection(stream) {
+                    eprintln!("Cannot answer the request: {}", err);
+                }
+            });
+        }
+
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(err) => {
+                    eprintln!("Cannot accept a connection: {}", err);
+                    continue;
+                }
+            };
+            match sender.try_send(stream) {
+                Ok(()) => (),
+                Err(mpsc::TrySendError::Full(mut stream)) => {
+                    let response = Response::error(503, "Too many connections, try again later");
+                    if let Err(err) = response.write_to(&mut stream) {
+                        eprintln!("Cannot turn away the connection: {}", err);
+                    }
+                }
+                Err(mpsc::TrySendError::Disconnected(_)) => {
+                    return Err(io::Error::other("Every worker has stopped"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// without any authentication the service must not be reachable from other machines
+pub fn bind_localhost(addr: &str) -> io::Result<TcpListener> {
+    let addr: SocketAddr = addr
+        .parse()
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Invalid address"))?;
+    if !addr.ip().is_loopback() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "Only loopback addresses can be served",
+        ));
+    }
+    TcpListener::bind(addr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Solution;
+    use crate::utils::ParseError;
+    use std::io::Cursor;
+
+    const DAY_1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn server(limits: Limits) -> Server {
+        Server::new(Registry::new(), Config::default(), limits)
+    }
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_read_request() {
+        let raw = format!(
+            "POST /solve/1/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            DAY_1.len(),
+            DAY_1
+        );
+        let request = read_request(&mut Cursor::new(raw.as_bytes()), 1024).unwrap();
+        assert_eq!(request, post("/solve/1/1", DAY_1));
+
+        let too_large = read_request(&mut Cursor::new(raw.as_bytes()), 8).unwrap_err();
+        assert_eq!(too_large.status, 413);
+
+        let garbage = read_request(&mut Cursor::new(&b"hello\r\n\r\n"[..]), 8).unwrap_err();
+        assert_eq!(garbage.status, 400);
+    }
+
+    #[test]
+    fn test_handle_solve() {
+        let server = server(Limits::default());
+
+        let response = server.handle(&post("/solve/1/2", DAY_1));
+        assert_eq!(response.status, 200);
+        assert!(response
+            .body
+            .starts_with(r#"{"year":2024,"day":1,"part":2,"answer":"31","time_ms":"#));
+
+        let response = server.handle(&post("/solve/2024/1/1", "3 4"));
+        assert_eq!(response.status, 422);
+        assert!(response.body.contains(r#""answer":null"#));
+
+        assert_eq!(server.handle(&post("/solve/26/1", DAY_1)).status, 404);
+        assert_eq!(server.handle(&post("/solve/1/3", DAY_1)).status, 404);
+        assert_eq!(server.handle(&post("/answers", DAY_1)).status, 404);
+        let mut get = post("/solve/1/1", "");
+        get.method = "GET".to_string();
+        assert_eq!(server.handle(&get).status, 405);
+    }
+
+    fn slow_solution() -> Solution {
+        Solution {
+            year: 2024,
+            day: 1,
+            title: "Slow",
+            version: "1",
+            source: "",
+            parts: [
+                |_| {
+                    thread::sleep(Duration::from_millis(200));
+                    Ok("slow".to_string())
+                },
+                |_| panic!("solver failure"),
+            ],
+            alternatives: vec![],
+            profile: |_| Err(ParseError::new("not profiled")),
+            explore: |_| Err(ParseError::new("not explorable")),
+        }
+    }
+
+    #[test]
+    fn test_handle_timeout() {
+        let limits = Limits {
+            timeout: Duration::from_millis(20),
+            max_running_solvers: 1,
+            ..Limits::default()
+        };
+        let registry = Registry::with_solutions(vec![slow_solution()]);
+        let server = Server::new(registry, Config::default(), limits);
+
+        let response = server.handle(&post("/solve/1/1", ""));
+        assert_eq!(response.status, 504);
+        assert!(response.body.contains("Timed out"));
+        // the timed out solver still occupies the only slot
+        assert_eq!(server.handle(&post("/solve/1/1", "")).status, 503);
+
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(server.handle(&post("/solve/1/1", "")).status, 504);
+    }
+
+    #[test]
+    fn test_handle_panic() {
+        let registry = Registry::with_solutions(vec![slow_solution()]);
+        let server = Server::new(registry, Config::default(), Limits::default());
+
+        let response = server.handle(&post("/solve/1/2", ""));
+        assert_eq!(response.status, 500);
+        assert!(response.body.contains("Solver panicked"));
+    }
+
+    #[test]
+    fn test_read_deadline() {
+        let listener = bind_localhost("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+        // every byte arrives well within the timeout, but the whole request does not
+        let trickle = thread::spawn(move || {
+            for byte in b"POST /solve/1/1 HTTP/1.1\r\n" {
+                if client.write_all(&[*byte]).is_err() {
+                    return;
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+        });
+
+        let start = Instant::now();
+        let mut reader = BufReader::new(Deadline {
+            stream,
+            deadline: start + Duration::from_millis(100),
+        });
+        let response = read_request(&mut reader, 1024).unwrap_err();
+        assert_eq!(response.status, 408);
+        assert!(start.elapsed() < Duration::from_millis(400));
+        drop(reader);
+        trickle.join().unwrap();
+    }
+
+    #[test]
+    fn test_worker_pool() {
+        let listener = bind_localhost("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let limits = Limits {
+            workers: 1,
+            ..Limits::default()
+        };
+        let registry = Registry::with_solutions(vec![slow_solution()]);
+        let server = Arc::new(Server::new(registry, Config::default(), limits));
+        thread::spawn(move || server.run(listener));
+
+        let request = |stream: &mut TcpStream| {
+            write!(
+                stream,
+                "POST /solve/1/1 HTTP/1.1\r\nContent-Length: 0\r\n\r\n"
+            )
+            .unwrap();
+        };
+        let mut busy = TcpStream::connect(addr).unwrap();
+        request(&mut busy);
+        // the only worker is solving by now
+        thread::sleep(Duration::from_millis(50));
+        let mut queued = TcpStream::connect(addr).unwrap();
+        request(&mut queued);
+        thread::sleep(Duration::from_millis(50));
+        let mut turned_away = TcpStream::connect(addr).unwrap();
+
+        let mut response = String::new();
+        turned_away.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503 "), "{}", response);
+        for mut stream in [busy, queued] {
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            assert!(response.contains(r#""answer":"slow""#), "{}", response);
+        }
+    }
+
+    #[test]
+    fn test_serve_over_tcp() {
+        let listener = bind_localhost("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = Arc::new(server(Limits::default()));
+        thread::spawn(move || server.run(listener));
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST /solve/1/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}",
+            DAY_1.len(),
+            DAY_1
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains(r#""answer":"11""#));
+        assert!(bind_localhost("0.0.0.0:0").is_err());
+    }
+}
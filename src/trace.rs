@@ -0,0 +1,153 @@
+use std::cell::Cell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_THREAD_ID: AtomicU64 = AtomicU64::new(1);
+static EVENTS: Mutex<Vec<Event>> = Mutex::new(Vec::new());
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+thread_local! {
+    static THREAD_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+// a complete ("X") event of the Chrome trace format, times in microseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Event {
+    pub name: &'static str,
+    pub ph: &'static str,
+    pub ts: f64,
+    pub dur: f64,
+    pub pid: u32,
+    pub tid: u64,
+}
+
+#[derive(Serialize)]
+struct ChromeTrace<'a> {
+    #[serde(rename = "traceEvents")]
+    trace_events: &'a [Event],
+    #[serde(rename = "displayTimeUnit")]
+    display_time_unit: &'static str,
+}
+
+pub fn enable() {
+    EPOCH.get_or_init(Instant::now);
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn thread_id() -> u64 {
+    THREAD_ID.with(|id| {
+        if id.get() == 0 {
+            id.set(NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed));
+        }
+        id.get()
+    })
+}
+
+// records the time until the returned guard is dropped, does nothing unless tracing is enabled
+pub fn span(name: &'static str) -> Span {
+    let start = match is_enabled() {
+        true => Some(Instant::now()),
+        false => None,
+    };
+    Span { name, start }
+}
+
+pub struct Span {
+    name: &'static str,
+    start: Option<Instant>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let epoch = *EPOCH.get_or_init(Instant::now);
+            let event = Event {
+                name: self.name,
+                ph: "X",
+                ts: start.saturating_duration_since(epoch).as_secs_f64() * 1e6,
+                dur: start.elapsed().as_secs_f64() * 1e6,
+                pid: std::process::id(),
+                tid: thread_id(),
+            };
+            EVENTS.lock().unwrap().push(event);
+        }
+    }
+}
+
+pub fn take_events() -> Vec<Event> {
+    std::mem::take(&mut *EVENTS.lock().unwrap())
+}
+
+pub fn chrome_json(events: &[Event]) -> String {
+    let trace = ChromeTrace {
+        trace_events: events,
+        display_time_unit: "ms",
+    };
+    serde_json::to_string(&trace).unwrap()
+}
+
+// drains the recorded events into a file loadable by chrome://tracing or Perfetto
+pub fn write_chrome_trace(path: impl AsRef<Path>) -> io::Result<usize> {
+    let events = take_events();
+    fs::write(path, chrome_json(&events))?;
+    Ok(events.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spans_are_recorded() {
+        {
+            let _span = span("test_disabled_span");
+        }
+        enable();
+        {
+            let _outer = span("test_outer_span");
+            let _inner = span("test_inner_span");
+        }
+        disable();
+
+        let events: Vec<_> = take_events()
+            .into_iter()
+            .filter(|event| event.name.starts_with("test_"))
+            .collect();
+        let names: Vec<_> = events.iter().map(|event| event.name).collect();
+        // inner guard is dropped first
+        assert_eq!(names, vec!["test_inner_span", "test_outer_span"]);
+        assert!(events[1].ts <= events[0].ts);
+        assert!(events[1].dur >= events[0].dur);
+    }
+
+    #[test]
+    fn test_chrome_json() {
+        let events = vec![Event {
+            name: "parse",
+            ph: "X",
+            ts: 1.5,
+            dur: 2.0,
+            pid: 7,
+            tid: 1,
+        }];
+        assert_eq!(
+            chrome_json(&events),
+            r#"{"traceEvents":[{"name":"parse","ph":"X","ts":1.5,"dur":2.0,"pid":7,"tid":1}],"displayTimeUnit":"ms"}"#
+        );
+    }
+}
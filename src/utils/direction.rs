@@ -0,0 +1,226 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ParseError, Vec2};
+
+// one of the four ways to move on a map, North is up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    // clockwise from North
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(0, -1),
+            Direction::East => Vec2::new(1, 0),
+            Direction::South => Vec2::new(0, 1),
+            Direction::West => Vec2::new(-1, 0),
+        }
+    }
+
+    // `^`, `>`, `v` and `<`
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    // an arrow or a compass letter
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        let direction = match (chars.next(), chars.next()) {
+            (Some('N'), None) => Some(Direction::North),
+            (Some('E'), None) => Some(Direction::East),
+            (Some('S'), None) => Some(Direction::South),
+            (Some('W'), None) => Some(Direction::West),
+            (Some(c), None) => Direction::from_arrow(c),
+            _ => None,
+        };
+        direction.ok_or_else(|| ParseError::new(format!("Invalid direction: {:?}", s)))
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Heading::from(*self))
+    }
+}
+
+// one of the eight ways to move on a map, including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Heading {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Heading {
+    // clockwise from N
+    pub const ALL: [Heading; 8] = [
+        Heading::N,
+        Heading::NE,
+        Heading::E,
+        Heading::SE,
+        Heading::S,
+        Heading::SW,
+        Heading::W,
+        Heading::NW,
+    ];
+
+    const NAMES: [&'static str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    // turns by 45 degrees
+    pub fn turn_right(self) -> Heading {
+        Heading::ALL[(self.index() + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Heading {
+        Heading::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Heading {
+        Heading::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Heading::N => Vec2::new(0, -1),
+            Heading::NE => Vec2::new(1, -1),
+            Heading::E => Vec2::new(1, 0),
+            Heading::SE => Vec2::new(1, 1),
+            Heading::S => Vec2::new(0, 1),
+            Heading::SW => Vec2::new(-1, 1),
+            Heading::W => Vec2::new(-1, 0),
+            Heading::NW => Vec2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Heading {
+    fn from(direction: Direction) -> Self {
+        Heading::ALL[direction.index() * 2]
+    }
+}
+
+impl FromStr for Heading {
+    type Err = ParseError;
+
+    // a compass point like `NE`, or an arrow
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match Heading::NAMES.iter().position(|name| *name == s) {
+            Some(index) => Ok(Heading::ALL[index]),
+            None => s.parse::<Direction>().map(Heading::from),
+        }
+    }
+}
+
+impl fmt::Display for Heading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Heading::NAMES[self.index()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.reverse(), Direction::East);
+        assert_eq!(Heading::NW.turn_right(), Heading::N);
+        assert_eq!(Heading::N.turn_left(), Heading::NW);
+        assert_eq!(Heading::SW.reverse(), Heading::NE);
+        assert!(Heading::SE.is_diagonal());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.offset(), Heading::from(direction).offset());
+            assert_eq!(direction.reverse().offset(), -direction.offset());
+        }
+        for heading in Heading::ALL {
+            assert_eq!(heading.reverse().offset(), -heading.offset());
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("^".parse(), Ok(Direction::North));
+        assert_eq!("W".parse(), Ok(Direction::West));
+        assert_eq!(Direction::from_arrow('v'), Some(Direction::South));
+        assert_eq!(Direction::from_arrow('N'), None);
+        assert!("NE".parse::<Direction>().is_err());
+        assert_eq!("NE".parse(), Ok(Heading::NE));
+        assert_eq!(">".parse(), Ok(Heading::E));
+        assert!("X".parse::<Heading>().is_err());
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+        for heading in Heading::ALL {
+            assert_eq!(heading.to_string().parse(), Ok(heading));
+        }
+    }
+}
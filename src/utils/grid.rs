@@ -0,0 +1,501 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Direction, Heading, ParseError, Point, Vec2};
+
+// (row, column), rows counted from the top
+pub type Position = (usize, usize);
+
+// the order neighbours are visited in
+const NEIGHBOURS_4: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+// a rectangular map stored row by row in one Vec
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawGrid<T>")]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// a grid as it was serialized, checked to be rectangular before it becomes a `Grid`
+#[derive(Deserialize)]
+struct RawGrid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> TryFrom<RawGrid<T>> for Grid<T> {
+    type Error = ParseError;
+
+    fn try_from(raw: RawGrid<T>) -> Result<Self, Self::Error> {
+        if raw.width == 0 || raw.height == 0 {
+            return Err(ParseError::new("Grid is empty"));
+        }
+        if raw.width.checked_mul(raw.height) != Some(raw.cells.len()) {
+            return Err(ParseError::new(format!(
+                "Grid of {} x {} has {} cells",
+                raw.width,
+                raw.height,
+                raw.cells.len()
+            )));
+        }
+        Ok(Grid {
+            width: raw.width,
+            height: raw.height,
+            cells: raw.cells,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if height == 0 || width == 0 {
+            return Err(ParseError::new("Grid is empty"));
+        }
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::new(format!(
+                    "Row {} has {} cells, expected {}",
+                    i,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    // one row per non-empty line, `glyph` maps every character to a cell
+    pub fn parse(s: &str, mut glyph: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+        for line in s.lines().filter(|line| !line.is_empty()) {
+            let mut row = Vec::with_capacity(line.len());
+            for (j, c) in line.chars().enumerate() {
+                match glyph(c) {
+                    Some(cell) => row.push(cell),
+                    None => {
+                        return Err(ParseError::new(format!(
+                            "Unknown glyph {:?} at row {}, column {}",
+                            c,
+                            rows.len(),
+                            j
+                        )))
+                    }
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point
+            .to_position()
+            .is_some_and(|(row, col)| row < self.height && col < self.width)
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        match row < self.height && col < self.width {
+            true => Some(&self.cells[row * self.width + col]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        match row < self.height && col < self.width {
+            true => Some(&mut self.cells[row * self.width + col]),
+            false => None,
+        }
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_position()?)
+    }
+
+    // `position` moved by `step`, if that is still on the grid
+    pub fn offset(&self, position: Position, step: Vec2) -> Option<Position> {
+        let point = Point::from(position) + step;
+        match self.contains(point) {
+            true => point.to_position(),
+            false => None,
+        }
+    }
+
+    pub fn swap(&mut self, (a_row, a_col): Position, (b_row, b_col): Position) {
+        self.cells
+            .swap(a_row * self.width + a_col, b_row * self.width + b_col);
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |direction| self.offset(position, direction.offset()))
+    }
+
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Heading::ALL
+            .iter()
+            .filter_map(move |heading| self.offset(position, heading.offset()))
+    }
+
+    // `start` and every position reached by repeating `step` until leaving the grid
+    pub fn ray(&self, start: Position, step: Vec2) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |position| {
+            self.offset(*position, step)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    // every line going down and to the right, starting from the left column or the top row
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|row| (row, 0))
+            .chain((1..self.width).map(|col| (0, col)));
+        starts.map(move |start| {
+            self.ray(start, Heading::SE.offset())
+                .map(move |position| &self[position])
+        })
+    }
+
+    // every line going down and to the left, starting from the top row or the right column
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_col = self.width - 1;
+        let starts = (0..self.width)
+            .map(|col| (0, col))
+            .chain((1..self.height).map(move |row| (row, last_col)));
+        starts.map(move |start| {
+            self.ray(start, Heading::SW.offset())
+                .map(move |position| &self[position])
+        })
+    }
+
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Position> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| predicate(cell)).count()
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // the same grid upside down
+    pub fn flip_rows(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.rows().rev().flatten().cloned().collect(),
+        }
+    }
+
+    // splits the grid into regions of cells connected through their 4 neighbours,
+    // two neighbours are in the same region when `same_region` says so
+    pub fn regions(&self, same_region: impl Fn(&T, &T) -> bool) -> Regions {
+        let mut labels: Grid<Option<usize>> = Grid::new(self.width, self.height, None);
+        let mut cells = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for start in self.positions() {
+            if labels[start].is_some() {
+                continue;
+            }
+            let label = cells.len();
+            let mut region = Vec::new();
+            labels[start] = Some(label);
+            queue.push_back(start);
+
+            while let Some(position) = queue.pop_front() {
+                for next in self.neighbours_4(position) {
+                    if labels[next].is_none() && same_region(&self[position], &self[next]) {
+                        labels[next] = Some(label);
+                        queue.push_back(next);
+                    }
+                }
+                region.push(position);
+            }
+            cells.push(region);
+        }
+
+        Regions {
+            labels: labels.map(|label| label.expect("Every cell is labelled")),
+            cells,
+        }
+    }
+}
+
+// the regions of a grid, numbered from 0 in the order their first cell appears
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    // the region of every cell
+    pub labels: Grid<usize>,
+    // the cells of every region
+    pub cells: Vec<Vec<Position>>,
+}
+
+impl Regions {
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // whether the cell at `step` from `position` is in the same region
+    pub fn same_region(&self, position: Position, step: Vec2) -> bool {
+        self.labels
+            .offset(position, step)
+            .is_some_and(|next| self.labels[next] == self.labels[position])
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Position) -> &T {
+        assert!(
+            row < self.height && col < self.width,
+            "Position {:?} is outside of the {}x{} grid",
+            (row, col),
+            self.height,
+            self.width
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Position) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "Position {:?} is outside of the {}x{} grid",
+            (row, col),
+            self.height,
+            self.width
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\n\n", Some).unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits = Grid::parse("12\n34\n", |c| c.to_digit(10));
+        assert_eq!(digits.map(|grid| grid[(1, 1)]), Ok(4));
+        assert!(Grid::parse("12\n3x\n", |c| c.to_digit(10)).is_err());
+        assert!(Grid::parse("12\n3\n", Some).is_err());
+        assert!(Grid::parse("\n", Some).is_err());
+    }
+
+    #[test]
+    fn test_checked_access() {
+        let mut grid = letters();
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_point(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get_point(Point::new(0, -1)), None);
+        assert_eq!(grid.offset((0, 0), Vec2::new(2, 1)), Some((1, 2)));
+        assert_eq!(grid.offset((0, 0), Vec2::new(-1, 0)), None);
+
+        grid.swap((0, 0), (1, 2));
+        *grid.get_mut((0, 1)).unwrap() = 'x';
+        assert_eq!(grid.to_string(), "fxc\ndea\n");
+        assert_eq!(grid.flip_rows().to_string(), "dea\nfxc\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = letters();
+        let neighbours: Vec<_> = grid.neighbours_4((0, 1)).collect();
+        assert_eq!(neighbours, vec![(0, 0), (1, 1), (0, 2)]);
+        assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = letters();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(collect(grid.columns()), vec!["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), vec!["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+        assert_eq!(grid.ray((0, 0), Direction::East.offset()).count(), 3);
+    }
+
+    #[test]
+    fn test_regions() {
+        let grid = Grid::parse("aab\nbab\nbab\n", Some).unwrap();
+        let regions = grid.regions(|a, b| a == b);
+
+        assert_eq!(regions.len(), 3);
+        assert_eq!(regions.cells[0], vec![(0, 0), (0, 1), (1, 1), (2, 1)]);
+        assert_eq!(regions.cells[1].len(), 3);
+        assert_eq!(regions.cells[2], vec![(1, 0), (2, 0)]);
+        assert_eq!(regions.labels.to_string(), "001\n201\n201\n");
+        assert!(regions.same_region((0, 1), Direction::South.offset()));
+        assert!(!regions.same_region((1, 0), Direction::East.offset()));
+        assert!(!regions.same_region((0, 0), Direction::North.offset()));
+
+        // one region covering a grid too big to flood fill recursively
+        let big = Grid::new(400, 400, 'a');
+        assert_eq!(big.regions(|a, b| a == b).cells[0].len(), 160_000);
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse("#.#\n..#\n", Some).unwrap();
+        assert_eq!(grid.position(|c| *c == '.'), Some((0, 1)));
+        assert_eq!(grid.position(|c| *c == '@'), None);
+        assert_eq!(
+            grid.find_all(|c| *c == '#').collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 2)]
+        );
+        assert_eq!(grid.count(|c| *c == '.'), 3);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'.')));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let grid = letters();
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(serde_json::from_str::<Grid<char>>(&json).unwrap(), grid);
+
+        for json in [
+            r#"{"width":2,"height":2,"cells":["a","b","c"]}"#,
+            r#"{"width":0,"height":0,"cells":[]}"#,
+            r#"{"width":0,"height":3,"cells":[]}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Grid<char>>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+        // corrupt d15 checkpoints fail to load instead of panicking later
+        let state = serde_json::json!({
+            "moves": [],
+            "next_move": 0,
+            "tiles": {"width": 3, "height": 1, "cells": ["Wall"]},
+            "wide_tiles": {"width": 1, "height": 1, "cells": ["Wall"]},
+        });
+        let checkpoint = crate::checkpoint::to_json(2024, 15, crate::y2024::d15::VERSION, &state);
+        let err = crate::y2024::d15::Simulation::from_checkpoint(&checkpoint).unwrap_err();
+        assert!(
+            err.to_string().contains("Grid of 3 x 1 has 1 cells"),
+            "{}",
+            err
+        );
+    }
+}
@@ -0,0 +1,143 @@
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// 0 when either is 0
+pub fn lcm(a: u64, b: u64) -> u64 {
+    match (a, b) {
+        (0, _) | (_, 0) => 0,
+        _ => a / gcd(a, b) * b,
+    }
+}
+
+// g = gcd(a, b) along with x and y such that a * x + b * y = g, g is never negative
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    match old_r < 0 {
+        true => (-old_r, -old_x, -old_y),
+        false => (old_r, old_x, old_y),
+    }
+}
+
+// the x in 0..modulus with a * x = 1 (mod modulus), if a and modulus are coprime
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "Modulus {} is not positive", modulus);
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus) as i128, modulus as i128);
+    match g == 1 {
+        true => Some(x.rem_euclid(modulus as i128) as i64),
+        false => None,
+    }
+}
+
+// every x with a * x = b (mod modulus), as (x, step) with x in 0..step, if there is any
+pub fn solve_congruence(a: i64, b: i64, modulus: i64) -> Option<(i64, i64)> {
+    assert!(modulus > 0, "Modulus {} is not positive", modulus);
+    let (a, b, modulus) = (a as i128, b as i128, modulus as i128);
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if b % g != 0 {
+        return None;
+    }
+    // a * x = g, so a * x * (b / g) = b
+    let step = modulus / g;
+    Some(((x * (b / g)).rem_euclid(step) as i64, step as i64))
+}
+
+// Chinese remainder theorem: the x with x = residue (mod modulus) for every pair, as
+// (x, lcm of the moduli) with x in 0..lcm. The moduli do not have to be coprime, but then the
+// residues have to agree where they overlap.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut lcm) = (0i128, 1i128);
+    for &(residue, modulus) in congruences {
+        assert!(modulus > 0, "Modulus {} is not positive", modulus);
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        let (g, inverse, _) = extended_gcd(lcm, modulus);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        // x + lcm * k = residue (mod modulus), so k = difference / g * inverse (mod modulus / g)
+        let step = modulus / g;
+        let k = (difference / g % step * inverse).rem_euclid(step);
+        x += lcm * k;
+        lcm *= step;
+        x = x.rem_euclid(lcm);
+    }
+    Some((x as i64, lcm as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(101, 103), 10403);
+        assert_eq!(lcm(0, 3), 0);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [
+            (240, 46),
+            (46, 240),
+            (-12, 18),
+            (12, -18),
+            (0, 5),
+            (5, 0),
+            (1, 1),
+        ] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(
+                g,
+                gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64) as i128
+            );
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(101, 103), Some(51));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_solve_congruence() {
+        assert_eq!(solve_congruence(3, 2, 7), Some((3, 7)));
+        assert_eq!(solve_congruence(6, 4, 10), Some((4, 5)));
+        assert_eq!(solve_congruence(6, 3, 10), None);
+        assert_eq!(solve_congruence(0, 10, 5), Some((0, 1)));
+        assert_eq!(solve_congruence(0, 3, 5), None);
+        assert_eq!(solve_congruence(-4, -2, 6), Some((2, 3)));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        // big enough for the product to overflow i64 without the wider arithmetic
+        let (x, modulus) = crt(&[(1, 1_000_000_007), (2, 998_244_353)]).unwrap();
+        assert_eq!(modulus, 1_000_000_007 * 998_244_353);
+        assert_eq!((x % 1_000_000_007, x % 998_244_353), (1, 2));
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+pub mod direction;
+pub mod grid;
+pub mod math;
+pub mod order;
+pub mod parse;
+pub mod point;
+pub mod search;
+
+pub use direction::{Direction, Heading};
+pub use grid::{Grid, Position, Regions};
+pub use point::{Point, Vec2};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn read_input(filepath: &str) -> String {
+    let input_filepath = match std::env::current_dir() {
+        Ok(cwd_filepath) => cwd_filepath.join(filepath),
+        Err(_) => panic!("Cannot find current directory"),
+    };
+    println!("Input filepath: {}", input_filepath.display());
+    fs::read_to_string(input_filepath).expect("File could not be loaded")
+}
+
+pub fn load_input<T>(filepath: &str) -> T
+where
+    T: FromStr<Err = ParseError>,
+{
+    match read_input(filepath).parse() {
+        Ok(input) => input,
+        Err(err) => panic!("Input could not be parsed: {}", err),
+    }
+}
+
+pub fn parse_number<T: FromStr>(value: &str) -> Result<T, ParseError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ParseError::new(format!("Invalid number: {:?}", value)))
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+// nodes ordered so that every `(before, after)` pair is respected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sorted<N> {
+    pub order: Vec<N>,
+    // false when some nodes could be swapped without breaking a pair, so other orders exist
+    pub unique: bool,
+}
+
+// nodes that all have to come before each other, each one before the next and the last before
+// the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: fmt::Display> fmt::Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cycle ")?;
+        for node in &self.0 {
+            write!(f, "{} -> ", node)?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{}", first),
+            None => write!(f, "()"),
+        }
+    }
+}
+
+impl<N: fmt::Debug + fmt::Display> std::error::Error for Cycle<N> {}
+
+// Kahn's algorithm over the pairs between `nodes`, pairs naming other nodes are ignored. Nodes
+// free to go from the start keep their order from `nodes` and the others go in the order they are
+// freed, and the order is unique when there is never more than one of them at a time.
+pub fn topological_sort<N>(nodes: &[N], pairs: &[(N, N)]) -> Result<Sorted<N>, Cycle<N>>
+where
+    N: Copy + Eq + Hash,
+{
+    let index: HashMap<N, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut after: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    let mut before: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (first, second) in pairs {
+        if let (Some(&first), Some(&second)) = (index.get(first), index.get(second)) {
+            after[first].push(second);
+            before[second].push(first);
+        }
+    }
+
+    let mut waiting: Vec<usize> = before.iter().map(Vec::len).collect();
+    let mut free: VecDeque<usize> = (0..nodes.len()).filter(|i| waiting[*i] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    let mut unique = true;
+    while let Some(node) = free.pop_front() {
+        unique &= free.is_empty();
+        order.push(nodes[node]);
+        for &next in &after[node] {
+            waiting[next] -= 1;
+            if waiting[next] == 0 {
+                free.push_back(next);
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        return Err(Cycle(find_cycle(&waiting, &before, nodes)));
+    }
+    Ok(Sorted { order, unique })
+}
+
+// Every node left over by the sort still waits for another left over node, so walking back
+// through those always runs into a node seen before, closing a cycle.
+fn find_cycle<N: Copy>(waiting: &[usize], before: &[Vec<usize>], nodes: &[N]) -> Vec<N> {
+    let mut seen = vec![None; nodes.len()];
+    let mut path = Vec::new();
+    let mut node = (0..nodes.len()).find(|i| waiting[*i] > 0).unwrap();
+    while seen[node].is_none() {
+        seen[node] = Some(path.len());
+        path.push(node);
+        node = *before[node]
+            .iter()
+            .find(|prev| waiting[**prev] > 0)
+            .unwrap();
+    }
+
+    // the walk went against the pairs, so the cycle is read backwards
+    let start = seen[node].unwrap();
+    path[start..].iter().rev().map(|i| nodes[*i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topological_sort() {
+        let pairs = [(1, 2), (2, 3), (1, 3), (4, 1), (9, 5)];
+        let sorted = topological_sort(&[3, 2, 1, 4], &pairs).unwrap();
+        assert_eq!(sorted.order, vec![4, 1, 2, 3]);
+        assert!(sorted.unique);
+
+        // nothing orders 5 and 7 against each other
+        let sorted = topological_sort(&[7, 3, 5], &[(7, 3), (5, 3)]).unwrap();
+        assert_eq!(sorted.order, vec![7, 5, 3]);
+        assert!(!sorted.unique);
+
+        // 1 comes first in the nodes, but is freed last
+        let sorted = topological_sort(&[1, 2, 3, 4], &[(4, 2), (3, 1), (4, 3)]).unwrap();
+        assert_eq!(sorted.order, vec![4, 2, 3, 1]);
+        assert!(!sorted.unique);
+
+        let sorted = topological_sort::<u64>(&[], &pairs).unwrap();
+        assert!(sorted.order.is_empty());
+    }
+
+    #[test]
+    fn test_cycle() {
+        let pairs = [(1, 2), (2, 3), (3, 4), (4, 2), (0, 1)];
+        let Err(Cycle(cycle)) = topological_sort(&[0, 1, 2, 3, 4], &pairs) else {
+            panic!("Expected a cycle");
+        };
+        assert_eq!(cycle.len(), 3);
+        for (i, node) in cycle.iter().enumerate() {
+            let next = cycle[(i + 1) % cycle.len()];
+            assert!(pairs.contains(&(*node, next)));
+        }
+        assert_eq!(Cycle(vec![2, 3, 4]).to_string(), "Cycle 2 -> 3 -> 4 -> 2");
+
+        // the cycle goes through 5, which is not sorted, so it does not count
+        let pairs = [(1, 2), (2, 5), (5, 1)];
+        assert!(topological_sort(&[1, 2], &pairs).is_ok());
+        assert_eq!(topological_sort(&[7], &[(7, 7)]), Err(Cycle(vec![7])));
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt;
+use std::str::FromStr;
+
+use regex::Regex;
+
+use super::ParseError;
+
+// why a single line could not be parsed, converted into a `ParseError` by the day parsers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineError {
+    NoMatch {
+        template: String,
+        line: String,
+    },
+    MissingSeparator {
+        separator: String,
+        line: String,
+    },
+    InvalidNumber {
+        value: String,
+        line: String,
+    },
+    WrongCount {
+        expected: usize,
+        found: usize,
+        line: String,
+    },
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineError::NoMatch { template, line } => {
+                write!(f, "Line {:?} does not match {:?}", line, template)
+            }
+            LineError::MissingSeparator { separator, line } => {
+                write!(f, "Line {:?} has no {:?}", line, separator)
+            }
+            LineError::InvalidNumber { value, line } => {
+                write!(f, "Invalid number {:?} in line {:?}", value, line)
+            }
+            LineError::WrongCount {
+                expected,
+                found,
+                line,
+            } => write!(
+                f,
+                "Expected {} numbers but found {} in line {:?}",
+                expected, found, line
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
+impl From<LineError> for ParseError {
+    fn from(err: LineError) -> Self {
+        ParseError::new(err.to_string())
+    }
+}
+
+fn parse_value<T: FromStr>(value: &str, line: &str) -> Result<T, LineError> {
+    value.parse().map_err(|_| LineError::InvalidNumber {
+        value: value.to_string(),
+        line: line.to_string(),
+    })
+}
+
+// A line layout like "Button {}: X+{i64}, Y+{i64}". `{}` matches any text, `{i64}`, `{u64}` and
+// the other integer types match a number, everything else has to appear as is.
+#[derive(Debug, Clone)]
+pub struct Template {
+    template: String,
+    regex: Regex,
+}
+
+impl Template {
+    // panics on an unknown placeholder, templates are written in the code
+    pub fn new(template: &str) -> Self {
+        let placeholder = Regex::new(r"\{([a-z0-9]*)\}").unwrap();
+        let mut pattern = String::from("^");
+        let mut end = 0;
+
+        for field in placeholder.captures_iter(template) {
+            let whole = field.get(0).unwrap();
+            pattern.push_str(&regex::escape(&template[end..whole.start()]));
+            pattern.push_str(match &field[1] {
+                "" => "(.*?)",
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => r"([+-]?\d+)",
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => r"(\+?\d+)",
+                other => panic!("Unknown placeholder {{{}}} in {:?}", other, template),
+            });
+            end = whole.end();
+        }
+        pattern.push_str(&regex::escape(&template[end..]));
+        pattern.push('$');
+
+        Template {
+            template: template.to_string(),
+            regex: Regex::new(&pattern).expect("Escaped templates are valid regexes"),
+        }
+    }
+
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Fields<'a>, LineError> {
+        match self.regex.captures(line) {
+            Some(captures) => Ok(Fields {
+                line,
+                values: captures
+                    .iter()
+                    .skip(1)
+                    .map(|value| value.map_or("", |value| value.as_str()))
+                    .collect(),
+            }),
+            None => Err(LineError::NoMatch {
+                template: self.template.clone(),
+                line: line.to_string(),
+            }),
+        }
+    }
+}
+
+// the text matched by every placeholder of a template, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fields<'a> {
+    line: &'a str,
+    values: Vec<&'a str>,
+}
+
+impl<'a> Fields<'a> {
+    pub fn text(&self, index: usize) -> &'a str {
+        self.values[index]
+    }
+
+    // fails when the number does not fit into `T`
+    pub fn get<T: FromStr>(&self, index: usize) -> Result<T, LineError> {
+        parse_value(self.values[index].trim_start_matches('+'), self.line)
+    }
+}
+
+// the lines with something on them
+pub fn lines(s: &str) -> impl Iterator<Item = &str> {
+    s.lines().filter(|line| !line.trim().is_empty())
+}
+
+// groups of lines separated by blank lines, without the trailing line break
+pub fn blocks(s: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let (mut start, mut end, mut offset) = (None, 0, 0);
+
+    for line in s.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                blocks.push(&s[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        blocks.push(&s[start..end]);
+    }
+
+    blocks
+}
+
+// the two sides of the first `separator`, trimmed
+pub fn split_pair<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), LineError> {
+    match line.split_once(separator) {
+        Some((left, right)) => Ok((left.trim(), right.trim())),
+        None => Err(LineError::MissingSeparator {
+            separator: separator.to_string(),
+            line: line.to_string(),
+        }),
+    }
+}
+
+// every value between `separator`s, empty values are skipped
+pub fn numbers<T: FromStr>(line: &str, separator: &str) -> Result<Vec<T>, LineError> {
+    line.split(separator)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| parse_value(value, line))
+        .collect()
+}
+
+// every integer in the line, whatever is around it. A `-` right before the digits is a sign
+// unless it follows a letter or a digit, so "x-3" and "1-3" have no negative numbers.
+pub fn extract_ints<T: FromStr>(line: &str) -> Result<Vec<T>, LineError> {
+    let bytes = line.as_bytes();
+    let mut ints = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start == 1 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        ints.push(parse_value(&line[start..i], line)?);
+    }
+
+    Ok(ints)
+}
+
+// exactly `N` integers
+pub fn extract_ints_array<T: FromStr, const N: usize>(line: &str) -> Result<[T; N], LineError> {
+    let ints: Vec<T> = extract_ints(line)?;
+    let found = ints.len();
+    ints.try_into().map_err(|_| LineError::WrongCount {
+        expected: N,
+        found,
+        line: line.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template() {
+        let button = Template::new("Button {}: X+{i64}, Y+{u64}");
+        let fields = button.captures("Button A: X+94, Y+34").unwrap();
+        assert_eq!(fields.text(0), "A");
+        assert_eq!(fields.get::<i64>(1), Ok(94));
+        assert_eq!(fields.get::<u64>(2), Ok(34));
+        assert!(fields.get::<u8>(0).is_err());
+
+        assert!(matches!(
+            button.captures("Button A: X+94, Y+-34"),
+            Err(LineError::NoMatch { .. })
+        ));
+        assert!(button.captures("Button A: X+94, Y+34 ").is_err());
+        assert_eq!(
+            Template::new("p={i64},{i64}")
+                .captures("p=-3,+4")
+                .and_then(|fields| Ok((fields.get::<i64>(0)?, fields.get::<i64>(1)?))),
+            Ok((-3, 4))
+        );
+        // `.` and `+` in the template are not regex syntax
+        assert!(Template::new("{u8}.{u8}").captures("1x2").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown placeholder {f64}")]
+    fn test_unknown_placeholder() {
+        Template::new("x={f64}");
+    }
+
+    #[test]
+    fn test_blocks_and_lines() {
+        let input = "a\nb\n\n\n c \n  \nd\n";
+        assert_eq!(blocks(input), vec!["a\nb", " c", "d"]);
+        assert_eq!(blocks("a\r\n\r\nb"), vec!["a", "b"]);
+        assert_eq!(blocks("\n\n"), Vec::<&str>::new());
+        assert_eq!(lines(input).collect::<Vec<_>>(), vec!["a", "b", " c ", "d"]);
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(split_pair("47|53", "|"), Ok(("47", "53")));
+        assert!(matches!(
+            split_pair("47,53", "|"),
+            Err(LineError::MissingSeparator { .. })
+        ));
+        assert_eq!(numbers::<u64>(" 7  6 4 ", " "), Ok(vec![7, 6, 4]));
+        assert_eq!(
+            numbers::<u64>("7,x", ","),
+            Err(LineError::InvalidNumber {
+                value: "x".to_string(),
+                line: "7,x".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_ints() {
+        assert_eq!(extract_ints::<i64>("p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(extract_ints::<i64>("x-3 1-3 (-2)"), Ok(vec![3, 1, 3, -2]));
+        assert_eq!(extract_ints::<i64>("none"), Ok(vec![]));
+        assert!(extract_ints::<u8>("300").is_err());
+        assert!(extract_ints::<u64>("-1").is_err());
+        assert_eq!(
+            extract_ints_array::<i64, 2>("X=8400, Y=5400"),
+            Ok([8400, 5400])
+        );
+        assert_eq!(
+            extract_ints_array::<i64, 3>("1 2"),
+            Err(LineError::WrongCount {
+                expected: 3,
+                found: 2,
+                line: "1 2".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+use super::Position;
+
+// x grows to the right and y grows down, like columns and rows of a map
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+// offsets and velocities, the difference between two points
+pub type Vec2 = Point;
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // the (row, column) of the point, if it is not left of or above the origin
+    pub fn to_position(self) -> Option<Position> {
+        match self.x >= 0 && self.y >= 0 {
+            true => Some((self.y as usize, self.x as usize)),
+            false => None,
+        }
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Self {
+        Point::new(col as i64, row as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Point::new(1, 2), Vec2::new(3, -4));
+        assert_eq!(a + b, Point::new(4, -2));
+        assert_eq!(a - b, Point::new(-2, 6));
+        assert_eq!(-b * 2, Vec2::new(-6, 8));
+        assert_eq!(a.manhattan(b), 8);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_positions() {
+        assert_eq!(Point::from((2, 5)), Point::new(5, 2));
+        assert_eq!(Point::new(5, 2).to_position(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 2).to_position(), None);
+    }
+}
@@ -0,0 +1,440 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// Every search takes a neighbour function instead of a graph, so it works on grid positions
+// as well as on any state type. None of them recurse, so deep searches cannot overflow the stack.
+
+// what a search from one or more starts reached: the distance and parent of every node
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, u64>,
+    parents: HashMap<N, N>,
+    order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    // every reached node, closest first
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.order.iter()
+    }
+
+    // from a start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        match self.contains(node) {
+            true => Some(reconstruct_path(&self.parents, node)),
+            false => None,
+        }
+    }
+}
+
+// follows `parents` back from `end` until a node without a parent, returns the path from there
+pub fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, end: &N) -> Vec<N> {
+    let mut path = vec![end.clone()];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+// every edge costs 1
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node];
+        for next in neighbours(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+        search.order.push(node);
+    }
+
+    search
+}
+
+// every node reachable from `start`, in the order a recursive depth first search would visit them
+pub fn dfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node.clone()) {
+            continue;
+        }
+        let next: Vec<_> = neighbours(&node).into_iter().collect();
+        stack.extend(
+            next.into_iter()
+                .rev()
+                .filter(|next| !visited.contains(next)),
+        );
+        order.push(node);
+    }
+
+    order
+}
+
+// best first search shared by Dijkstra and A*, stops at the first settled node matching `is_goal`
+fn best_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Search<N>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    let mut settled = HashSet::new();
+    // the heap holds indices into `nodes`, so nodes do not need to be ordered
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    // A node found again for less after it was settled goes back on the heap, which only
+    // happens with a heuristic that is not consistent. Entries for a distance that has been
+    // improved on since are skipped.
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if distance > search.distances[&node] {
+            continue;
+        }
+        if settled.insert(node.clone()) {
+            search.order.push(node.clone());
+        }
+        if is_goal(&node) {
+            return (search, Some(node));
+        }
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if search
+                .distance(&next)
+                .is_none_or(|known| next_distance < known)
+            {
+                search.distances.insert(next.clone(), next_distance);
+                search.parents.insert(next.clone(), node.clone());
+                let estimate = next_distance + heuristic(&next);
+                heap.push(Reverse((estimate, next_distance, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+
+    (search, None)
+}
+
+// `neighbours` returns every next node with the cost to get there
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    best_first(starts, neighbours, |_| 0, |_| false).0
+}
+
+// the cheapest path to a goal and its cost, `heuristic` must never overestimate the remaining
+// cost but does not have to be consistent
+pub fn astar<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> u64,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (search, goal) = best_first([start], neighbours, heuristic, is_goal);
+    let goal = goal?;
+    Some((
+        reconstruct_path(&search.parents, &goal),
+        search.distances[&goal],
+    ))
+}
+
+// every shortest path from the starts, as a DAG of predecessors
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    distances: HashMap<N, u64>,
+    predecessors: HashMap<N, Vec<N>>,
+    order: Vec<N>,
+}
+
+impl<N: Clone + Eq + Hash> ShortestPaths<N> {
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    // the nodes right before `node` on its shortest paths, empty for the starts
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors
+            .get(node)
+            .map_or(&[], |nodes| nodes.as_slice())
+    }
+
+    // every reached node, closest first
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.order.iter()
+    }
+
+    // one of the shortest paths to `node`
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let parents: HashMap<N, N> = self
+            .predecessors
+            .iter()
+            .filter_map(|(node, predecessors)| Some((node.clone(), predecessors.first()?.clone())))
+            .collect();
+        Some(reconstruct_path(&parents, node))
+    }
+
+    // how many different shortest paths reach every node
+    pub fn path_counts(&self) -> HashMap<N, u64> {
+        let mut counts: HashMap<N, u64> = HashMap::new();
+        // predecessors are always settled before the nodes they lead to
+        for node in &self.order {
+            let count = match self.predecessors(node) {
+                [] => 1,
+                predecessors => predecessors.iter().map(|node| counts[node]).sum(),
+            };
+            counts.insert(node.clone(), count);
+        }
+        counts
+    }
+
+    // every node on at least one shortest path to one of `ends`, the ends included
+    pub fn nodes_on_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let ends = ends
+            .into_iter()
+            .filter(|end| self.distances.contains_key(end));
+        bfs(ends, |node| self.predecessors(node).to_vec())
+            .reached()
+            .cloned()
+            .collect()
+    }
+}
+
+// like `dijkstra`, but keeps every predecessor on a shortest path, costs must be positive
+pub fn all_shortest_paths<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> ShortestPaths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut paths = ShortestPaths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        order: Vec::new(),
+    };
+    let mut settled = HashSet::new();
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if paths.distances.insert(start.clone(), 0).is_none() {
+            heap.push(Reverse((0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((distance, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if !settled.insert(node.clone()) {
+            continue;
+        }
+        paths.order.push(node.clone());
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            match paths.distance(&next) {
+                Some(known) if next_distance > known => (),
+                Some(known) if next_distance == known => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                }
+                _ => {
+                    paths.distances.insert(next.clone(), next_distance);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    heap.push(Reverse((next_distance, nodes.len())));
+                    nodes.push(next);
+                }
+            }
+        }
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Grid;
+
+    const MAZE: &str = "
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    fn open(maze: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        maze.neighbours_4(position)
+            .filter(|next| maze[*next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let start = maze.position(|c| *c == 'S').unwrap();
+        let end = maze.position(|c| *c == 'E').unwrap();
+        let search = bfs([start], |position| open(&maze, *position));
+
+        assert_eq!(search.distance(&end), Some(15));
+        let path = search.path_to(&end).unwrap();
+        assert_eq!(
+            (path.first(), path.last(), path.len()),
+            (Some(&start), Some(&end), 16)
+        );
+        assert_eq!(search.reached().next(), Some(&start));
+        assert_eq!(search.path_to(&(0, 3)), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let order = dfs(0, |n| match *n {
+            0 => vec![1, 2],
+            1 => vec![3],
+            2 => vec![3, 0],
+            _ => vec![],
+        });
+        assert_eq!(order, vec![0, 1, 3, 2]);
+
+        // deep enough to overflow the stack if it recursed
+        assert_eq!(
+            dfs(0u32, |n| (*n < 100_000).then_some(n + 1)).len(),
+            100_001
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // 0 -> 1 -> 2 costs 2, 0 -> 2 directly costs 5
+        let edges = |n: &u32| match *n {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(2, 1)],
+            _ => vec![],
+        };
+        let search = dijkstra([0], edges);
+        assert_eq!(search.distance(&2), Some(2));
+        assert_eq!(search.path_to(&2), Some(vec![0, 1, 2]));
+
+        let maze = maze();
+        let start = maze.position(|c| *c == 'S').unwrap();
+        let end = maze.position(|c| *c == 'E').unwrap();
+        let steps = |position: &(usize, usize)| open(&maze, *position).into_iter().map(|p| (p, 1));
+        let distance = |position: &(usize, usize)| {
+            crate::utils::Point::from(*position).manhattan(crate::utils::Point::from(end))
+        };
+        let (path, cost) = astar(start, steps, distance, |position| *position == end).unwrap();
+        assert_eq!((cost, path.len()), (15, 16));
+        assert_eq!(
+            astar(start, steps, |_| 0, |position| *position == (0, 3)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // 'C' is first reached straight from 'S' for 4, and only later through 'A' for 2
+        let edges = |n: &char| match *n {
+            'S' => vec![('A', 1), ('C', 4)],
+            'A' => vec![('C', 1)],
+            'C' => vec![('G', 5)],
+            _ => vec![],
+        };
+        // never more than the real 6 left from 'A', but more than the 1 + 0 of going to 'C'
+        let heuristic = |n: &char| if *n == 'A' { 6 } else { 0 };
+        assert_eq!(
+            astar('S', edges, heuristic, |n| *n == 'G'),
+            Some((vec!['S', 'A', 'C', 'G'], 7))
+        );
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        // a diamond: two shortest paths to 3, 4 is only reached the long way
+        let edges = |n: &u32| match *n {
+            0 => vec![(1, 1), (2, 1)],
+            1 | 2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        };
+        let paths = all_shortest_paths([0], edges);
+        assert_eq!(paths.distance(&4), Some(3));
+        assert_eq!(paths.predecessors(&3), &[1, 2]);
+        assert_eq!(paths.predecessors(&0), &[] as &[u32]);
+        assert_eq!(paths.path_counts()[&4], 2);
+        assert_eq!(paths.path_to(&4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(paths.nodes_on_paths([3]), HashSet::from([0, 1, 2, 3]));
+    }
+}
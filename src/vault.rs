@@ -0,0 +1,295 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use sha2::{Digest, Sha256};
+
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+const MAGIC: &[u8; 8] = b"AOCVAULT";
+const KEY_BYTES: usize = 32;
+const NONCE_BYTES: usize = 24;
+const FINGERPRINT_BYTES: usize = 8;
+
+fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+pub fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    getrandom::fill(&mut bytes).expect("No system randomness available");
+    bytes
+}
+
+pub fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key {
+    bytes: [u8; KEY_BYTES],
+}
+
+// never print the key itself
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key({})", to_hex(&self.fingerprint()))
+    }
+}
+
+impl Key {
+    pub fn generate() -> Self {
+        Key {
+            bytes: random_bytes(),
+        }
+    }
+
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let bytes = from_hex(hex.trim())?;
+        Some(Key {
+            bytes: bytes.try_into().ok()?,
+        })
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.bytes)
+    }
+
+    // identifies the key an input was encrypted with without revealing it
+    pub fn fingerprint(&self) -> [u8; FINGERPRINT_BYTES] {
+        let hash = sha256(&[b"aoc vault key", &self.bytes]);
+        hash[..FINGERPRINT_BYTES].try_into().unwrap()
+    }
+
+    pub fn load(path: &Path) -> io::Result<Key> {
+        let hex = fs::read_to_string(path).map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("Cannot read the vault key {}: {}", path.display(), err),
+            )
+        })?;
+        Key::from_hex(&hex)
+            .ok_or_else(|| invalid_data(format!("Invalid vault key in {}", path.display())))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, self.to_hex() + "\n")?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&tmp_path, fs::Permissions::from_mode(0o600))?;
+        }
+        fs::rename(tmp_path, path)
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.bytes.into())
+    }
+
+    // MAGIC, key fingerprint, nonce, ciphertext with the authentication tag
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce: [u8; NONCE_BYTES] = random_bytes();
+        let ciphertext = self
+            .cipher()
+            .encrypt(&XNonce::from(nonce), plaintext)
+            .expect("Encryption cannot fail for in-memory buffers");
+
+        let mut sealed =
+            Vec::with_capacity(MAGIC.len() + FINGERPRINT_BYTES + NONCE_BYTES + ciphertext.len());
+        sealed.extend_from_slice(MAGIC);
+        sealed.extend_from_slice(&self.fingerprint());
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        sealed
+    }
+
+    pub fn decrypt(&self, sealed: &[u8]) -> io::Result<Vec<u8>> {
+        let header = MAGIC.len() + FINGERPRINT_BYTES + NONCE_BYTES;
+        if sealed.len() < header || &sealed[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("Not an encrypted input"));
+        }
+        let fingerprint = &sealed[MAGIC.len()..MAGIC.len() + FINGERPRINT_BYTES];
+        if fingerprint != self.fingerprint() {
+            return Err(invalid_data(format!(
+                "Encrypted with key {}, but the vault key is {}",
+                to_hex(fingerprint),
+                to_hex(&self.fingerprint())
+            )));
+        }
+        let nonce: [u8; NONCE_BYTES] = sealed[header - NONCE_BYTES..header].try_into().unwrap();
+        self.cipher()
+            .decrypt(&XNonce::from(nonce), &sealed[header..])
+            .map_err(|_| invalid_data("Encrypted input is corrupted"))
+    }
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(ENCRYPTED_EXTENSION);
+    path.with_file_name(file_name)
+}
+
+// plain text inputs win, otherwise `<path>.enc` is decrypted with the key from `key_file`
+pub fn read_input(path: &Path, key_file: &Path) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+        result => return result,
+    }
+
+    let sealed_path = encrypted_path(path);
+    let sealed = match fs::read(&sealed_path) {
+        Ok(sealed) => sealed,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Neither {} nor {} exist",
+                    path.display(),
+                    sealed_path.display()
+                ),
+            ))
+        }
+        Err(err) => return Err(err),
+    };
+    let plaintext = Key::load(key_file)?.decrypt(&sealed)?;
+    String::from_utf8(plaintext).map_err(|_| invalid_data("Decrypted input is not valid UTF-8"))
+}
+
+pub fn add_input(key: &Key, source: &Path, destination: &Path) -> io::Result<PathBuf> {
+    let plaintext = fs::read(source)?;
+    let sealed_path = encrypted_path(destination);
+    if let Some(dir) = sealed_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&sealed_path, key.encrypt(&plaintext))?;
+    Ok(sealed_path)
+}
+
+pub fn encrypted_inputs(input_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut inputs = Vec::new();
+    let years = match fs::read_dir(input_dir) {
+        Ok(years) => years,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(inputs),
+        Err(err) => return Err(err),
+    };
+    for year in years {
+        let year = year?.path();
+        if !year.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(year)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|ext| ext == ENCRYPTED_EXTENSION)
+            {
+                inputs.push(path);
+            }
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+// every input is decrypted before anything is written, so a wrong key leaves the vault untouched
+pub fn rotate(old_key: &Key, new_key: &Key, input_dir: &Path) -> io::Result<usize> {
+    let mut plaintexts = Vec::new();
+    for path in encrypted_inputs(input_dir)? {
+        let plaintext = old_key
+            .decrypt(&fs::read(&path)?)
+            .map_err(|err| invalid_data(format!("{}: {}", path.display(), err)))?;
+        plaintexts.push((path, plaintext));
+    }
+
+    for (path, plaintext) in &plaintexts {
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, new_key.encrypt(plaintext))?;
+        fs::rename(tmp_path, path)?;
+    }
+    Ok(plaintexts.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-vault-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(to_hex(&[0, 15, 255]), "000fff");
+        assert_eq!(from_hex("000fff"), Some(vec![0, 15, 255]));
+        assert_eq!(from_hex("0f0"), None);
+        assert_eq!(from_hex("zz"), None);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key = Key::generate();
+        let sealed = key.encrypt(b"3   4\n4   3\n");
+
+        assert!(!sealed.windows(5).any(|window| window == b"3   4"));
+        assert_eq!(key.decrypt(&sealed).unwrap(), b"3   4\n4   3\n");
+        assert!(Key::generate().decrypt(&sealed).is_err());
+
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(key.decrypt(&tampered).is_err());
+        assert_eq!(Key::from_hex(&key.to_hex()), Some(key));
+    }
+
+    #[test]
+    fn test_vault_files() {
+        let dir = temp_dir("files");
+        let key_file = dir.join("vault.key");
+        let input_dir = dir.join("inputs");
+        let key = Key::generate();
+        key.save(&key_file).unwrap();
+
+        let source = dir.join("download");
+        fs::write(&source, "125 17\n").unwrap();
+        let input = input_dir.join("2024").join("input_11");
+        let sealed_path = add_input(&key, &source, &input).unwrap();
+        assert_eq!(sealed_path, input_dir.join("2024").join("input_11.enc"));
+        assert_eq!(read_input(&input, &key_file).unwrap(), "125 17\n");
+
+        let new_key = Key::generate();
+        assert!(rotate(&new_key, &key, &input_dir).is_err());
+        assert_eq!(rotate(&key, &new_key, &input_dir).unwrap(), 1);
+        assert!(read_input(&input, &key_file).is_err());
+        new_key.save(&key_file).unwrap();
+        assert_eq!(read_input(&input, &key_file).unwrap(), "125 17\n");
+
+        assert!(read_input(&input_dir.join("2024").join("input_12"), &key_file).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::parse::{self, Template};
+use crate::utils::{self, ParseError};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub left: Vec<u64>,
+    pub right: Vec<u64>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pair = Template::new("{u64}   {u64}");
+        let mut left_vec: Vec<u64> = Vec::new();
+        let mut right_vec: Vec<u64> = Vec::new();
+        for line in parse::lines(s) {
+            let fields = pair.captures(line)?;
+            left_vec.push(fields.get(0)?);
+            right_vec.push(fields.get(1)?);
+        }
+        Ok(Input {
+            left: left_vec,
+            right: right_vec,
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (left, right) in std::iter::zip(&self.left, &self.right) {
+            writeln!(f, "{}   {}", left, right)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let mut left_vec = input.left.clone();
+    let mut right_vec = input.right.clone();
+    left_vec.sort();
+    right_vec.sort();
+
+    std::iter::zip(left_vec, right_vec)
+        .map(|(l, r)| l.abs_diff(r))
+        .sum()
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let mut res = 0;
+
+    for num in &input.left {
+        res += num * (input.right.iter().filter(|&v| v == num).count() as u64);
+    }
+
+    res
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_01_test"), 11);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_01_test"), 31);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_01_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
@@ -1,30 +1,43 @@
-#[path = "utils.rs"]
-mod utils;
-
-fn load_levels(filepath: &str) -> Vec<Vec<u64>> {
-    let lines = utils::read_file(filepath);
-    let mut levels = Vec::new();
-    for line in lines {
-        let mut single_vec = Vec::new();
-        for value in line.split(" ") {
-            let num_str = value.trim();
-            if !num_str.is_empty() {
-                single_vec.push(num_str.parse().unwrap());
-            }
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, parse, ParseError};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub reports: Vec<Vec<u64>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut reports = Vec::new();
+        for line in parse::lines(s) {
+            reports.push(parse::numbers(line, " ")?);
         }
-        if !single_vec.is_empty() {
-            levels.push(single_vec);
+        Ok(Input { reports })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for levels_vec in &self.reports {
+            let levels: Vec<_> = levels_vec.iter().map(|l| l.to_string()).collect();
+            writeln!(f, "{}", levels.join(" "))?;
         }
+        Ok(())
     }
-    levels
 }
 
 fn check_diff(first: u64, second: u64) -> bool {
     let diff = first - second;
-    return diff > 3;
+    diff > 3
 }
 
-fn is_safe(levels_vec: &Vec<u64>) -> bool {
+fn is_safe(levels_vec: &[u64]) -> bool {
     let num_of_elems = levels_vec.len();
     if num_of_elems > 1 {
         let (first, second) = (levels_vec[0], levels_vec[1]);
@@ -57,22 +70,15 @@ fn is_safe(levels_vec: &Vec<u64>) -> bool {
     true
 }
 
-pub fn solve_part_1(filepath: &str) -> usize {
-    let levels = load_levels(filepath);
-
-    levels
-        .iter()
-        .filter_map(|x| if is_safe(&x) { Some(x) } else { None })
-        .count()
+pub fn part_1(input: &Input) -> usize {
+    input.reports.iter().filter(|x| is_safe(x)).count()
 }
 
-pub fn solve_part_2(filepath: &str) -> usize {
-    let levels = load_levels(filepath);
-
+pub fn part_2(input: &Input) -> usize {
     let mut res = 0;
 
-    for levels_vec in levels {
-        if is_safe(&levels_vec) {
+    for levels_vec in &input.reports {
+        if is_safe(levels_vec) {
             res += 1;
             continue;
         }
@@ -90,17 +96,31 @@ pub fn solve_part_2(filepath: &str) -> usize {
     res
 }
 
+pub fn solve_part_1(filepath: &str) -> usize {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> usize {
+    part_2(&utils::load_input(filepath))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(solve_part_1("input_02_test"), 2);
+        assert_eq!(solve_part_1("inputs/2024/input_02_test"), 2);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(solve_part_2("input_02_test"), 4);
+        assert_eq!(solve_part_2("inputs/2024/input_02_test"), 4);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_02_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
     }
 }
@@ -1,8 +1,33 @@
-#[path = "utils.rs"]
-mod utils;
+use std::fmt;
+use std::str::FromStr;
 
 use regex::Regex;
 
+use crate::utils::{self, ParseError};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub memory: String,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input {
+            memory: s.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.memory)
+    }
+}
+
 enum Operation {
     Multiply,
 }
@@ -19,12 +44,11 @@ fn find_values(s: &str) -> Vec<(u64, u64)> {
     values
 }
 
-fn load_operations(filepath: &str) -> Vec<(Operation, u64, u64)> {
-    let lines = utils::read_file(filepath);
+fn load_operations(input: &Input) -> Vec<(Operation, u64, u64)> {
     let mut operations = Vec::new();
 
-    for line in lines {
-        for (first, second) in find_values(&line) {
+    for line in input.memory.lines() {
+        for (first, second) in find_values(line) {
             operations.push((Operation::Multiply, first, second));
         }
     }
@@ -32,8 +56,8 @@ fn load_operations(filepath: &str) -> Vec<(Operation, u64, u64)> {
     operations
 }
 
-pub fn solve_part_1(filepath: &str) -> u64 {
-    let operations = load_operations(filepath);
+pub fn part_1(input: &Input) -> u64 {
+    let operations = load_operations(input);
     let mut res = 0;
 
     for (_op, first, second) in operations {
@@ -43,14 +67,13 @@ pub fn solve_part_1(filepath: &str) -> u64 {
     res
 }
 
-fn load_operations_part2(filepath: &str) -> Vec<(Operation, u64, u64)> {
-    let lines = utils::read_file(filepath);
+fn load_operations_part2(input: &Input) -> Vec<(Operation, u64, u64)> {
     let mut operations = Vec::new();
 
     let mut enabled = true;
-    for line in lines {
+    for line in input.memory.lines() {
         let mut curr_idx = 0;
-        let mut curr_buff = line.clone();
+        let mut curr_buff = line.to_string();
         while curr_idx <= line.len() {
             curr_buff = curr_buff[curr_idx..].to_string();
             if enabled {
@@ -95,8 +118,8 @@ fn load_operations_part2(filepath: &str) -> Vec<(Operation, u64, u64)> {
     operations
 }
 
-pub fn solve_part_2(filepath: &str) -> u64 {
-    let operations = load_operations_part2(filepath);
+pub fn part_2(input: &Input) -> u64 {
+    let operations = load_operations_part2(input);
     let mut res = 0;
 
     for (_op, first, second) in operations {
@@ -107,17 +130,31 @@ pub fn solve_part_2(filepath: &str) -> u64 {
     res
 }
 
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(solve_part_1("input_03_test"), 161);
+        assert_eq!(solve_part_1("inputs/2024/input_03_test"), 161);
     }
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(solve_part_2("input_03_test"), 48);
+        assert_eq!(solve_part_2("inputs/2024/input_03_test"), 48);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_03_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
     }
 }
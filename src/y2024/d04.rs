@@ -0,0 +1,121 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, Grid, Heading, ParseError, Position};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub letters: Grid<char>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Input {
+            letters: Grid::parse(s, Some)?,
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letters)
+    }
+}
+
+fn count_words<'a>(line: impl Iterator<Item = &'a char>, word: &str) -> u64 {
+    let line = String::from_iter(line);
+    let rev_word = String::from_iter(word.chars().rev());
+    // XMAS and SAMX matches
+    (line.match_indices(word).count() + line.match_indices(&rev_word).count()) as u64
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let letters = &input.letters;
+    let word_to_find = "XMAS";
+    let mut res = 0;
+
+    for row in letters.rows() {
+        res += count_words(row.iter(), word_to_find);
+    }
+    for column in letters.columns() {
+        res += count_words(column, word_to_find);
+    }
+    for diagonal in letters.diagonals() {
+        res += count_words(diagonal, word_to_find);
+    }
+    for diagonal in letters.anti_diagonals() {
+        res += count_words(diagonal, word_to_find);
+    }
+
+    res
+}
+
+fn check_if_mas(text: String) -> bool {
+    let search = "MAS";
+    text == search || text == String::from_iter(search.chars().rev())
+}
+
+fn check_xmas(letters: &Grid<char>, position: Position) -> bool {
+    // the three letters crossing `position` from `from` to the opposite corner
+    let diagonal = |from: Heading| -> Option<String> {
+        let to = letters.offset(position, from.reverse().offset())?;
+        let from = letters.offset(position, from.offset())?;
+        Some(String::from_iter([
+            letters[from],
+            letters[position],
+            letters[to],
+        ]))
+    };
+
+    match (diagonal(Heading::NW), diagonal(Heading::NE)) {
+        (Some(first), Some(second)) => check_if_mas(first) && check_if_mas(second),
+        _ => false,
+    }
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let letters = &input.letters;
+    let mut res = 0;
+
+    for (i, j) in letters.find_all(|letter| *letter == 'A') {
+        println!("A found at {} {}", i, j);
+        if check_xmas(letters, (i, j)) {
+            res += 1;
+        }
+    }
+
+    res
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_04_test"), 18);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_04_test"), 9);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_04_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
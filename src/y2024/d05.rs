@@ -0,0 +1,252 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::order::{self, Cycle, Sorted};
+use crate::utils::parse::{self, Template};
+use crate::utils::{self, ParseError};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub rules: Vec<(u64, u64)>,
+    pub print_orders: Vec<Vec<u64>>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rule = Template::new("{u64}|{u64}");
+        let mut rules = Vec::new();
+        let mut print_orders = Vec::new();
+
+        for line in parse::lines(s) {
+            if line.contains("|") {
+                let fields = rule.captures(line)?;
+                rules.push((fields.get(0)?, fields.get(1)?));
+            } else {
+                print_orders.push(parse::numbers(line, ",")?);
+            }
+        }
+
+        Ok(Input {
+            rules,
+            print_orders,
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (first, second) in &self.rules {
+            writeln!(f, "{}|{}", first, second)?;
+        }
+        writeln!(f)?;
+        for print_order in &self.print_orders {
+            let pages: Vec<_> = print_order.iter().map(|p| p.to_string()).collect();
+            writeln!(f, "{}", pages.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+// why an update cannot be put in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateError {
+    Cycle(Cycle<u64>),
+    // the rules leave the update more than one order, so there is no telling its middle page
+    Ambiguous(Vec<u64>),
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateError::Cycle(cycle) => write!(f, "{}", cycle),
+            UpdateError::Ambiguous(update) => {
+                let pages: Vec<_> = update.iter().map(|p| p.to_string()).collect();
+                write!(f, "Update {} can be ordered in more ways", pages.join(","))
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateError {}
+
+impl From<Cycle<u64>> for UpdateError {
+    fn from(cycle: Cycle<u64>) -> Self {
+        UpdateError::Cycle(cycle)
+    }
+}
+
+fn load_rules(rules: &[(u64, u64)]) -> HashMap<u64, HashSet<u64>> {
+    let mut map: HashMap<u64, HashSet<u64>> = HashMap::new();
+
+    for (first_num, second_num) in rules {
+        map.entry(*first_num).or_default().insert(*second_num);
+    }
+
+    map
+}
+
+fn check_print_order(print_order: &[u64], rules: &HashMap<u64, HashSet<u64>>) -> bool {
+    for i in 0..print_order.len() {
+        let val = print_order[i];
+
+        for prev in 0..i {
+            let prev_val = print_order[prev];
+            if let Some(ruleset) = rules.get(&val) {
+                if ruleset.contains(&prev_val) {
+                    println!("Rule not followed: {} before {}", prev_val, val);
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
+
+// the pages sorted by the rules between them, rules about pages not in the update do not matter
+fn fix_ordering(
+    print_order: &[u64],
+    rules: &HashMap<u64, HashSet<u64>>,
+) -> Result<Sorted<u64>, Cycle<u64>> {
+    let pages: HashSet<u64> = print_order.iter().copied().collect();
+    let induced: Vec<(u64, u64)> = print_order
+        .iter()
+        .filter_map(|page| Some((page, rules.get(page)?)))
+        .flat_map(|(page, after)| {
+            after
+                .iter()
+                .filter(|next| pages.contains(next))
+                .map(|next| (*page, *next))
+        })
+        .collect();
+    order::topological_sort(print_order, &induced)
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let mut res = 0;
+
+    let rules = load_rules(&input.rules);
+    let print_orders = &input.print_orders;
+
+    for print_order in print_orders {
+        if check_print_order(print_order, &rules) {
+            let val = print_order.len() / 2;
+            res += print_order[val];
+        }
+    }
+
+    res
+}
+
+// fails when the rules between the pages of an update go round in a cycle, or do not settle
+// their order
+pub fn part_2(input: &Input) -> Result<u64, UpdateError> {
+    let mut res = 0;
+
+    let rules = load_rules(&input.rules);
+    let print_orders = &input.print_orders;
+
+    for print_order in print_orders {
+        if !check_print_order(print_order, &rules) {
+            let sorted = fix_ordering(print_order, &rules)?;
+            if !sorted.unique {
+                return Err(UpdateError::Ambiguous(print_order.clone()));
+            }
+            let new_values = sorted.order;
+            let val = new_values[new_values.len() / 2];
+            res += val;
+        }
+    }
+
+    Ok(res)
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> Result<u64, UpdateError> {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_05_test"), 143);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_05_test"), Ok(123));
+    }
+
+    #[test]
+    fn test_fix_ordering() {
+        let input: Input = utils::load_input("inputs/2024/input_05_test");
+        let rules = load_rules(&input.rules);
+        let sorted = fix_ordering(&[97, 13, 75, 29, 47], &rules).unwrap();
+        assert_eq!(sorted.order, vec![97, 75, 47, 29, 13]);
+        assert!(sorted.unique);
+
+        // no rule between 13 and 99
+        let sorted = fix_ordering(&[13, 99], &rules).unwrap();
+        assert!(!sorted.unique);
+
+        let rules = load_rules(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+        assert_eq!(
+            fix_ordering(&[4, 3, 2, 1], &rules),
+            Err(Cycle(vec![1, 2, 3]))
+        );
+        // without 2 the rules no longer go round
+        assert!(fix_ordering(&[4, 3, 1], &rules).is_ok());
+    }
+
+    #[test]
+    fn test_cycle_in_update() {
+        let input = "1|2\n2|1\n\n2,1\n";
+        assert_eq!(
+            part_2(&input.parse().unwrap()),
+            Err(UpdateError::Cycle(Cycle(vec![1, 2])))
+        );
+
+        let registry = crate::registry::Registry::new();
+        let solution = registry.get(2024, 5).unwrap();
+        assert_eq!(
+            solution.solve(2, input).map_err(|err| err.to_string()),
+            Err("Cycle 1 -> 2 -> 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_update() {
+        // nothing orders 1 and 3, both only have to come before 2
+        let input = "1|2\n3|2\n\n2,1,3\n";
+        assert_eq!(
+            part_2(&input.parse().unwrap()),
+            Err(UpdateError::Ambiguous(vec![2, 1, 3]))
+        );
+        // updates already in order are left as they are
+        assert_eq!(part_2(&"1|2\n3|2\n\n1,3,2\n".parse().unwrap()), Ok(0));
+
+        let registry = crate::registry::Registry::new();
+        let solution = registry.get(2024, 5).unwrap();
+        assert_eq!(
+            solution.solve(2, input).map_err(|err| err.to_string()),
+            Err("Update 2,1,3 can be ordered in more ways".to_string())
+        );
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_05_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
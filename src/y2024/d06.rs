@@ -0,0 +1,217 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::params;
+use crate::trace;
+use crate::utils::{self, Direction, Grid, ParseError, Position};
+
+pub const VERSION: &str = "1";
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Tile {
+    Visited(Vec<Direction>),
+    Empty,
+    Obstacle,
+    Guard,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match self {
+            Tile::Visited(_) => 'X',
+            Tile::Empty => '.',
+            Tile::Obstacle => '#',
+            Tile::Guard => '^',
+        };
+        write!(f, "{}", glyph)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub map: Grid<Tile>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |char| match char {
+            '#' => Some(Tile::Obstacle),
+            '.' => Some(Tile::Empty),
+            '^' => Some(Tile::Guard),
+            _ => None,
+        })?;
+        Ok(Input { map })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+fn find_guard(map: &Grid<Tile>) -> Option<Position> {
+    map.position(|tile| *tile == Tile::Guard)
+}
+
+fn simulate_movement(map: &Grid<Tile>) -> Grid<Tile> {
+    let mut explored_map = map.clone();
+    println!("Map size {} x {}", map.height(), map.width());
+
+    if let Some(guard) = find_guard(map) {
+        let mut map = map.clone();
+        map[guard] = Tile::Empty;
+        let mut guard_direction = Direction::North;
+        let mut guard_pos = guard;
+
+        while let Some(next) = map.offset(guard_pos, guard_direction.offset()) {
+            explored_map[guard_pos] = Tile::Visited(vec![guard_direction]);
+            match map[next] {
+                Tile::Empty => guard_pos = next,
+                Tile::Obstacle => guard_direction = guard_direction.turn_right(),
+                _ => (),
+            }
+        }
+        explored_map[guard_pos] = Tile::Visited(vec![guard_direction]);
+    }
+
+    explored_map
+}
+
+fn detect_loops(map: &Grid<Tile>) -> bool {
+    let mut explored_map = map.clone();
+
+    if let Some(guard) = find_guard(map) {
+        explored_map[guard] = Tile::Empty;
+        let mut guard_direction = Direction::North;
+        let mut guard_pos = guard;
+
+        while let Some(next) = explored_map.offset(guard_pos, guard_direction.offset()) {
+            match &mut explored_map[guard_pos] {
+                Tile::Visited(vec) => vec.push(guard_direction),
+                Tile::Empty => {
+                    explored_map[guard_pos] = Tile::Visited(vec![guard_direction]);
+                }
+                Tile::Obstacle => todo!(),
+                Tile::Guard => todo!(),
+            }
+            match &explored_map[next] {
+                Tile::Empty => guard_pos = next,
+                Tile::Obstacle => guard_direction = guard_direction.turn_right(),
+                Tile::Visited(directions) => {
+                    if directions.contains(&guard_direction) {
+                        return true;
+                    }
+                    guard_pos = next;
+                }
+                _ => (),
+            }
+
+            // we are back at start
+            if guard == guard_pos && guard_direction == Direction::North {
+                return true;
+            }
+        }
+        explored_map[guard_pos] = Tile::Visited(vec![guard_direction]);
+    }
+
+    false
+}
+
+fn find_loops_in_rows(map: &Grid<Tile>, rows: std::ops::Range<usize>) -> u64 {
+    let mut loops = 0;
+
+    for i in rows {
+        for j in 0..map.width() {
+            let mut explored_map = {
+                let _span = trace::span("clone_map");
+                map.clone()
+            };
+            if let Tile::Empty = &explored_map[(i, j)] {
+                explored_map[(i, j)] = Tile::Obstacle;
+                let _span = trace::span("detect_loops");
+                if detect_loops(&explored_map) {
+                    println!("Detected loop if inserted at {} {}", i, j);
+                    loops += 1;
+                }
+            }
+        }
+    }
+    loops
+}
+
+// every obstacle position is checked independently, so rows are split between threads
+fn find_loops(map: &Grid<Tile>) -> u64 {
+    let num_rows = map.height();
+    println!("Map size {} x {}", num_rows, map.width());
+
+    let threads = params::get("threads", 1).clamp(1, num_rows);
+    let rows_per_thread = num_rows.div_ceil(threads);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..num_rows)
+            .step_by(rows_per_thread)
+            .map(|start| {
+                let rows = start..(start + rows_per_thread).min(num_rows);
+                scope.spawn(move || find_loops_in_rows(map, rows))
+            })
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .sum()
+    })
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let explored_map = {
+        let _span = trace::span("simulate_movement");
+        simulate_movement(&input.map)
+    };
+
+    explored_map.count(|tile| matches!(tile, Tile::Visited(_))) as u64
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    find_loops(&input.map)
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_06_test"), 41);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_06_test"), 6);
+    }
+
+    #[test]
+    fn test_example_part2_threads() {
+        let input: Input = utils::load_input("inputs/2024/input_06_test");
+        let mut threads = params::Params::new();
+        threads.set("threads", "4");
+
+        assert_eq!(params::with_params(&threads, || part_2(&input)), 6);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_06_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
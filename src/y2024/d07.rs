@@ -0,0 +1,152 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, parse, ParseError};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equation {
+    pub result: u64,
+    pub values: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub equations: Vec<Equation>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut equations = Vec::new();
+
+        for line in parse::lines(s) {
+            let (result, values) = parse::split_pair(line, ":")?;
+            let result = utils::parse_number(result)?;
+            let values = parse::numbers(values, " ")?;
+            if values.is_empty() {
+                return Err(ParseError::new(format!(
+                    "Equation has no values: {:?}",
+                    line
+                )));
+            }
+            equations.push(Equation { result, values });
+        }
+
+        Ok(Input { equations })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for equation in &self.equations {
+            let values: Vec<_> = equation.values.iter().map(|v| v.to_string()).collect();
+            writeln!(f, "{}: {}", equation.result, values.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+fn is_valid(equation: &Equation) -> bool {
+    let (eq_res, values) = (&equation.result, &equation.values);
+
+    let mut possible_res = Vec::new();
+
+    possible_res.push(values[0]);
+
+    for i in 1..values.len() {
+        let res_copy = possible_res.clone();
+        possible_res.clear();
+
+        for val in res_copy {
+            let mul_result = val * values[i];
+            possible_res.push(mul_result);
+            let sum_result = val + values[i];
+            possible_res.push(sum_result);
+        }
+    }
+
+    possible_res.contains(eq_res)
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let mut res = 0;
+
+    for equation in &input.equations {
+        if is_valid(equation) {
+            println!("Equation: {:?} is valid", equation);
+            res += equation.result;
+        }
+    }
+    res
+}
+
+fn is_valid_part_2(equation: &Equation) -> bool {
+    let (eq_res, values) = (&equation.result, &equation.values);
+
+    let mut possible_res = Vec::new();
+
+    possible_res.push(values[0]);
+
+    for i in 1..values.len() {
+        let res_copy = possible_res.clone();
+        possible_res.clear();
+
+        for val in res_copy {
+            let mul_result = val * values[i];
+            possible_res.push(mul_result);
+
+            let sum_result = val + values[i];
+            possible_res.push(sum_result);
+
+            let concat_res = format!("{}{}", val, values[i]).parse().unwrap();
+            possible_res.push(concat_res);
+        }
+    }
+
+    possible_res.contains(eq_res)
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let mut res = 0;
+
+    for equation in &input.equations {
+        if is_valid_part_2(equation) {
+            println!("Equation: {:?} is valid", equation);
+            res += equation.result;
+        }
+    }
+
+    res
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_07_test"), 3749);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_07_test"), 11387);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_07_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
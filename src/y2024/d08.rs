@@ -0,0 +1,140 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, Grid, ParseError, Point, Position};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Antena(char),
+    Empty,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Antena(c) => write!(f, "{}", c),
+            Tile::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub map: Grid<Tile>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |c| match c {
+            '.' => Some(Tile::Empty),
+            _ => Some(Tile::Antena(c)),
+        })?;
+        Ok(Input { map })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+fn find_antenas(map: &Grid<Tile>) -> HashMap<char, Vec<Position>> {
+    let mut antenas_locations: HashMap<char, Vec<Position>> = HashMap::new();
+
+    for (position, tile) in map.iter() {
+        if let Tile::Antena(c) = tile {
+            antenas_locations.entry(*c).or_default().push(position);
+        }
+    }
+
+    antenas_locations
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let antenas = &input.map;
+    let mut antinodes: HashSet<Position> = HashSet::new();
+
+    for (antena_type, locations) in &find_antenas(antenas) {
+        println!("Analysing antenas {} {:?}", antena_type, locations);
+        for (i, current_antena) in locations.iter().enumerate() {
+            for next_antena in &locations[i + 1..locations.len()] {
+                println!("Analysing antenas {:?}", next_antena);
+                let distance = Point::from(*next_antena) - Point::from(*current_antena);
+
+                if let Some(antinode) = antenas.offset(*next_antena, distance) {
+                    println!("Analysing antinode {:?}", antinode);
+                    antinodes.insert(antinode);
+                }
+                if let Some(antinode) = antenas.offset(*current_antena, -distance) {
+                    println!("Analysing antinode {:?}", antinode);
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+
+    println!("{}", antinodes.len());
+
+    antinodes.len() as u64
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let antenas = &input.map;
+    let mut antinodes: HashSet<Position> = HashSet::new();
+
+    for (antena_type, locations) in &find_antenas(antenas) {
+        println!("Analysing antenas {} {:?}", antena_type, locations);
+        for (i, current_antena) in locations.iter().enumerate() {
+            for next_antena in &locations[i + 1..locations.len()] {
+                println!("Analysing antenas {:?}", next_antena);
+                let distance = Point::from(*next_antena) - Point::from(*current_antena);
+
+                // both rays start on an antena, so the antenas are antinodes too
+                antinodes.extend(antenas.ray(*next_antena, distance));
+                antinodes.extend(antenas.ray(*current_antena, -distance));
+            }
+        }
+    }
+
+    antinodes.len() as u64
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_08_test"), 14);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_08_test"), 34);
+    }
+
+    #[test]
+    fn test_example_part2_test_2() {
+        assert_eq!(solve_part_2("inputs/2024/input_08_test2"), 9);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_08_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
@@ -1,36 +1,65 @@
-#[path = "utils.rs"]
-mod utils;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, ParseError};
+
+pub const VERSION: &str = "1";
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum FilesystemItem {
+pub enum FilesystemItem {
     FileBlock(u32),
     Empty,
 }
 
-fn load_lines(filepath: &str) -> Vec<String> {
-    let lines = utils::read_file(filepath);
-    lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub disk_map: Vec<u32>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut disk_map = Vec::new();
+
+        for line in s.lines() {
+            for c in line.chars() {
+                match c.to_digit(10) {
+                    Some(num_of_blocks) => disk_map.push(num_of_blocks),
+                    None => return Err(ParseError::new(format!("Invalid block size: {:?}", c))),
+                }
+            }
+        }
+
+        if disk_map.is_empty() {
+            return Err(ParseError::new("Disk map is empty"));
+        }
+        Ok(Input { disk_map })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for num_of_blocks in &self.disk_map {
+            write!(f, "{}", num_of_blocks)?;
+        }
+        writeln!(f)
+    }
 }
 
-fn load_filesystem_layout(lines: &Vec<String>) -> Vec<FilesystemItem> {
+pub fn load_filesystem_layout(input: &Input) -> Vec<FilesystemItem> {
     let mut filesystem_layout = Vec::new();
 
     let mut file_id = 0;
-    for line in lines {
-        if line.is_empty() {
-            continue;
-        }
-        for (i, c) in line.chars().enumerate() {
-            let num_of_blocks = c.to_string().parse::<u32>().unwrap();
-            if i % 2 == 0 {
-                for _block_nr in 0..num_of_blocks {
-                    filesystem_layout.push(FilesystemItem::FileBlock(file_id));
-                }
-                file_id += 1;
-            } else {
-                for _block_nr in 0..num_of_blocks {
-                    filesystem_layout.push(FilesystemItem::Empty);
-                }
+    for (i, num_of_blocks) in input.disk_map.iter().enumerate() {
+        if i % 2 == 0 {
+            for _block_nr in 0..*num_of_blocks {
+                filesystem_layout.push(FilesystemItem::FileBlock(file_id));
+            }
+            file_id += 1;
+        } else {
+            for _block_nr in 0..*num_of_blocks {
+                filesystem_layout.push(FilesystemItem::Empty);
             }
         }
     }
@@ -38,7 +67,7 @@ fn load_filesystem_layout(lines: &Vec<String>) -> Vec<FilesystemItem> {
     filesystem_layout
 }
 
-fn calculate_checksum(filesystem_layout: &Vec<FilesystemItem>) -> u64 {
+fn calculate_checksum(filesystem_layout: &[FilesystemItem]) -> u64 {
     let mut res = 0;
 
     for (i, filesystem_item) in filesystem_layout.iter().enumerate() {
@@ -51,8 +80,8 @@ fn calculate_checksum(filesystem_layout: &Vec<FilesystemItem>) -> u64 {
     res
 }
 
-fn defragment_fs(filesystem_layout: &Vec<FilesystemItem>) -> Vec<FilesystemItem> {
-    let mut defragmented = filesystem_layout.clone();
+fn defragment_fs(filesystem_layout: &[FilesystemItem]) -> Vec<FilesystemItem> {
+    let mut defragmented = filesystem_layout.to_vec();
 
     let mut reversed_fs_items = Vec::new();
     for (i, item) in filesystem_layout.iter().enumerate() {
@@ -90,10 +119,8 @@ fn defragment_fs(filesystem_layout: &Vec<FilesystemItem>) -> Vec<FilesystemItem>
     defragmented
 }
 
-pub fn solve_part_1(filepath: &str) -> u64 {
-    let lines = load_lines(filepath);
-
-    let fs_layout = load_filesystem_layout(&lines);
+pub fn part_1(input: &Input) -> u64 {
+    let fs_layout = load_filesystem_layout(input);
 
     let defragmented_fs = defragment_fs(&fs_layout);
 
@@ -101,7 +128,7 @@ pub fn solve_part_1(filepath: &str) -> u64 {
 }
 
 fn get_fs_items(
-    filesystem_flattened_layout: &Vec<(FilesystemItem, u64)>,
+    filesystem_flattened_layout: &[(FilesystemItem, u64)],
 ) -> Vec<(usize, (FilesystemItem, u64))> {
     let mut reversed_fs_items = Vec::new();
     for (i, (item, size)) in filesystem_flattened_layout.iter().enumerate().rev() {
@@ -115,17 +142,17 @@ fn get_fs_items(
     reversed_fs_items
 }
 
-fn defragment_fs_part_2(filesystem_layout: &Vec<FilesystemItem>) -> Vec<FilesystemItem> {
+fn defragment_fs_part_2(filesystem_layout: &[FilesystemItem]) -> Vec<FilesystemItem> {
     let mut filesystem_flattened_layout: Vec<(FilesystemItem, u64)> = Vec::new();
 
-    let (mut prev_item, mut count) = (filesystem_layout[0].clone(), 1);
+    let (mut prev_item, mut count) = (filesystem_layout[0], 1);
     for i in 1..filesystem_layout.len() {
         let item = &filesystem_layout[i];
         if *item != prev_item {
             filesystem_flattened_layout.push((prev_item, count));
 
             count = 0;
-            prev_item = item.clone();
+            prev_item = *item;
         }
         count += 1;
     }
@@ -141,7 +168,7 @@ fn defragment_fs_part_2(filesystem_layout: &Vec<FilesystemItem>) -> Vec<Filesyst
             .unwrap();
 
         for i in 0..current_fs_item_idx {
-            let (item, empty_block_size) = filesystem_flattened_layout[i].clone();
+            let (item, empty_block_size) = filesystem_flattened_layout[i];
             match item {
                 FilesystemItem::FileBlock(_) => (),
                 FilesystemItem::Empty => {
@@ -150,7 +177,7 @@ fn defragment_fs_part_2(filesystem_layout: &Vec<FilesystemItem>) -> Vec<Filesyst
                             (FilesystemItem::Empty, empty_block_size - size);
                         filesystem_flattened_layout[current_fs_item_idx] =
                             (FilesystemItem::Empty, size);
-                        filesystem_flattened_layout.insert(i, (fs_item.clone(), size));
+                        filesystem_flattened_layout.insert(i, (fs_item, size));
                         break;
                     }
                 }
@@ -162,34 +189,41 @@ fn defragment_fs_part_2(filesystem_layout: &Vec<FilesystemItem>) -> Vec<Filesyst
 
     for (item, amount) in &filesystem_flattened_layout {
         for _ in 0..*amount {
-            defragmented.push(item.clone());
+            defragmented.push(*item);
         }
     }
 
     defragmented
 }
-pub fn solve_part_2(filepath: &str) -> u64 {
-    let lines = load_lines(filepath);
 
-    let fs_layout = load_filesystem_layout(&lines);
+pub fn part_2(input: &Input) -> u64 {
+    let fs_layout = load_filesystem_layout(input);
     let defragmented_fs = defragment_fs_part_2(&fs_layout);
 
     calculate_checksum(&defragmented_fs)
 }
 
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_example_part1() {
-        assert_eq!(solve_part_1("input_09_test"), 1928);
+        assert_eq!(solve_part_1("inputs/2024/input_09_test"), 1928);
     }
 
     #[test]
     fn test_loading_layout() {
         assert_eq!(
-            load_filesystem_layout(&vec!["12345".to_string()]),
+            load_filesystem_layout(&"12345".parse().unwrap()),
             vec![
                 FilesystemItem::FileBlock(0),
                 FilesystemItem::Empty,
@@ -212,6 +246,12 @@ mod tests {
 
     #[test]
     fn test_example_part2() {
-        assert_eq!(solve_part_2("input_09_test"), 2858);
+        assert_eq!(solve_part_2("inputs/2024/input_09_test"), 2858);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_09_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
     }
 }
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, search, Grid, ParseError, Position};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MapScale {
+    Height(u8),
+}
+
+impl fmt::Display for MapScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let MapScale::Height(h) = self;
+        write!(f, "{}", h)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub map: Grid<MapScale>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |c| c.to_digit(10).map(|h| MapScale::Height(h as u8)))?;
+        Ok(Input { map })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+fn find_starting_points(map: &Grid<MapScale>) -> Vec<Position> {
+    map.find_all(|tile| *tile == MapScale::Height(0)).collect()
+}
+
+// the neighbours exactly one height higher
+fn uphill(map: &Grid<MapScale>, position: Position) -> impl Iterator<Item = Position> + '_ {
+    let MapScale::Height(height) = map[position];
+    map.neighbours_4(position)
+        .filter(move |next| map[*next] == MapScale::Height(height + 1))
+}
+
+// how many tops can be reached from the trailhead
+fn calculate_trailhead_score(map: &Grid<MapScale>, position: Position) -> u64 {
+    search::bfs([position], |position| uphill(map, *position))
+        .reached()
+        .filter(|position| map[**position] == MapScale::Height(9))
+        .count() as u64
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let map = &input.map;
+    let mut res = 0;
+
+    let start_points = find_starting_points(map);
+
+    for start_point in start_points {
+        let current_score = calculate_trailhead_score(map, start_point);
+        res += current_score;
+    }
+
+    res
+}
+
+// how many trails lead to a top, every trail is a shortest path as it climbs one height per step
+fn calculate_trailhead_rating(map: &Grid<MapScale>, position: Position) -> u64 {
+    let trails = search::all_shortest_paths([position], |position| {
+        uphill(map, *position).map(|next| (next, 1))
+    });
+    trails
+        .path_counts()
+        .iter()
+        .filter(|(position, _)| map[**position] == MapScale::Height(9))
+        .map(|(_, count)| count)
+        .sum()
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let map = &input.map;
+
+    let mut res = 0;
+    let start_points = find_starting_points(map);
+
+    for start_point in start_points {
+        let current_score = calculate_trailhead_rating(map, start_point);
+        res += current_score;
+    }
+
+    res
+}
+
+// walks the trails one height at a time keeping only the set of reached tiles
+pub fn part_1_layered(input: &Input) -> u64 {
+    let map = &input.map;
+    let mut res = 0;
+
+    for start_point in find_starting_points(map) {
+        let mut reached: HashSet<Position> = HashSet::from([start_point]);
+        for height in 1..=9 {
+            reached = reached
+                .iter()
+                .flat_map(|position| map.neighbours_4(*position))
+                .filter(|position| map[*position] == MapScale::Height(height))
+                .collect();
+        }
+        res += reached.len() as u64;
+    }
+
+    res
+}
+
+// counts the trails reaching every tile, going from the lowest height up
+pub fn part_2_layered(input: &Input) -> u64 {
+    let map = &input.map;
+    let mut trails: Grid<u64> = map.map(|tile| (*tile == MapScale::Height(0)) as u64);
+
+    for height in 1..=9 {
+        for position in map.find_all(|tile| *tile == MapScale::Height(height)) {
+            trails[position] = map
+                .neighbours_4(position)
+                .filter(|neighbour| map[*neighbour] == MapScale::Height(height - 1))
+                .map(|neighbour| trails[neighbour])
+                .sum();
+        }
+    }
+
+    map.find_all(|tile| *tile == MapScale::Height(9))
+        .map(|position| trails[position])
+        .sum()
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_10_test"), 36);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_10_test"), 81);
+    }
+
+    #[test]
+    fn test_layered_matches_recursion() {
+        let input: Input = utils::load_input("inputs/2024/input_10_test");
+        assert_eq!(part_1_layered(&input), 36);
+        assert_eq!(part_2_layered(&input), 81);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_10_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
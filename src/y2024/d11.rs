@@ -1,16 +1,41 @@
-#[path = "utils.rs"]
-mod utils;
-
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::params;
+use crate::utils::{self, parse, ParseError};
+
+pub const VERSION: &str = "1";
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 struct Stone {
     value: u64,
 }
 
-fn load_lines(filepath: &str) -> Vec<String> {
-    let lines = utils::read_file(filepath);
-    lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub stones: Vec<u64>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut stones = Vec::new();
+
+        for line in parse::lines(s) {
+            stones.extend(parse::numbers::<u64>(line, " ")?);
+        }
+
+        Ok(Input { stones })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stones: Vec<_> = self.stones.iter().map(|s| s.to_string()).collect();
+        writeln!(f, "{}", stones.join(" "))
+    }
 }
 
 fn get_parsed_stones(
@@ -22,7 +47,7 @@ fn get_parsed_stones(
         None => {
             let stones_str = stone_val.to_string();
             let stones_len = stones_str.len();
-            if stones_len % 2 == 0 {
+            if stones_len.is_multiple_of(2) {
                 let new_stones = (
                     Stone {
                         value: stones_str[0..stones_len / 2].to_string().parse().unwrap(),
@@ -63,15 +88,11 @@ fn process_stones(stones: &HashMap<u64, u64>) -> HashMap<u64, u64> {
     new_stones
 }
 
-fn load_stones_to_map(lines: &Vec<String>) -> HashMap<u64, u64> {
+fn load_stones_to_map(input: &Input) -> HashMap<u64, u64> {
     let mut stones = HashMap::new();
 
-    for line in lines {
-        if !line.is_empty() {
-            for stone in line.split(" ") {
-                insert_stone(&mut stones, stone.parse().unwrap(), 1);
-            }
-        }
+    for stone in &input.stones {
+        insert_stone(&mut stones, *stone, 1);
     }
 
     stones
@@ -96,35 +117,41 @@ fn transform_stones(stones: &HashMap<u64, u64>, epochs: usize) -> HashMap<u64, u
     new_stones
 }
 
-pub fn solve_part_1(filepath: &str) -> u64 {
-    let lines = load_lines(filepath);
-    let stones = load_stones_to_map(&lines);
+pub fn part_1(input: &Input) -> u64 {
+    let stones = load_stones_to_map(input);
 
-    let epochs = 25;
+    let epochs = params::get("blinks_part_1", 25);
     let result_stones = transform_stones(&stones, epochs);
 
     result_stones.values().sum()
 }
 
-pub fn solve_part_2(filepath: &str) -> u64 {
-    let lines = load_lines(filepath);
-    let stones = load_stones_to_map(&lines);
+pub fn part_2(input: &Input) -> u64 {
+    let stones = load_stones_to_map(input);
 
-    let epochs = 75;
+    let epochs = params::get("blinks_part_2", 75);
     let result_stones = transform_stones(&stones, epochs);
 
     result_stones.values().sum()
 }
 
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_example_part1() {
-        let lines = load_lines("input_11_test");
+        let input = utils::load_input("inputs/2024/input_11_test");
 
-        let mut stones = load_stones_to_map(&lines);
+        let mut stones = load_stones_to_map(&input);
 
         stones = process_stones(&stones);
 
@@ -133,11 +160,17 @@ mod tests {
 
     #[test]
     fn test_example_another_example() {
-        let stones_loaded_from_file = vec!["125 17".to_string()];
+        let stones_loaded_from_file = "125 17".parse().unwrap();
 
         let stones = load_stones_to_map(&stones_loaded_from_file);
 
         let new_stones = transform_stones(&stones, 25);
         assert_eq!(new_stones.values().sum::<u64>(), 55312);
     }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_11_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
 }
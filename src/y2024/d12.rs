@@ -0,0 +1,175 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::{self, Direction, Grid, Heading, ParseError, Position, Regions};
+
+pub const VERSION: &str = "1";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Plant {
+    pub kind: char,
+}
+
+impl fmt::Display for Plant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub plants: Grid<Plant>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let plants = Grid::parse(s, |c| Some(Plant { kind: c }))?;
+        Ok(Input { plants })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.plants)
+    }
+}
+
+fn find_regions(plants: &Grid<Plant>) -> Regions {
+    plants.regions(|plant, neighbour| plant == neighbour)
+}
+
+// every side of a plant not shared with the same region is fence
+fn calculate_perimeter(regions: &Regions, plant: Position) -> u64 {
+    Direction::ALL
+        .iter()
+        .filter(|direction| !regions.same_region(plant, direction.offset()))
+        .count() as u64
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let regions = find_regions(&input.plants);
+
+    regions
+        .cells
+        .iter()
+        .map(|region| {
+            let perimeter: u64 = region
+                .iter()
+                .map(|plant| calculate_perimeter(&regions, *plant))
+                .sum();
+            perimeter * region.len() as u64
+        })
+        .sum()
+}
+
+// a region has as many sides as vertices
+fn calculate_vertices(regions: &Regions, plant: Position) -> u64 {
+    let mut vertices = 0;
+    let same_region = |heading: Heading| regions.same_region(plant, heading.offset());
+
+    // every corner is checked against the two sides next to it
+    for (corner, vertical, horizontal) in [
+        (Heading::NW, Heading::N, Heading::W),
+        (Heading::NE, Heading::N, Heading::E),
+        (Heading::SW, Heading::S, Heading::W),
+        (Heading::SE, Heading::S, Heading::E),
+    ] {
+        let diagonal = same_region(corner);
+        let vertical = same_region(vertical);
+        let horizontal = same_region(horizontal);
+
+        // vertices for the given tile
+        // something is a vertice if the tile at the diagonal is in another region
+        // and the neighbour tiles are both in another region or both in the current one
+        // * -
+        // - -
+        // or
+        // * *
+        // * -
+        // In both above cases the last minus has a vertice at the upper left side
+        if !diagonal && vertical == horizontal {
+            vertices += 1;
+        }
+
+        // Edge case where the region touches itself on the diagonal
+        // * -
+        // - *
+        if diagonal && !vertical && !horizontal {
+            vertices += 1;
+        }
+    }
+
+    vertices
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let regions = find_regions(&input.plants);
+
+    regions
+        .cells
+        .iter()
+        .map(|region| {
+            let sides: u64 = region
+                .iter()
+                .map(|plant| calculate_vertices(&regions, *plant))
+                .sum();
+            sides * region.len() as u64
+        })
+        .sum()
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_12_test_2"), 772);
+    }
+
+    #[test]
+    fn test_bigger_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_12_test"), 1930);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_12_test_3"), 236);
+    }
+
+    #[test]
+    fn test_bigger_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_12_test"), 1206);
+    }
+
+    #[test]
+    fn test_another_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_12_test_4"), 368);
+    }
+
+    #[test]
+    fn test_one_big_region() {
+        let input: Input = format!("{}\n", "A".repeat(300))
+            .repeat(300)
+            .parse()
+            .unwrap();
+        assert_eq!(part_1(&input), 300 * 300 * 4 * 300);
+        assert_eq!(part_2(&input), 300 * 300 * 4);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_12_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
@@ -0,0 +1,994 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::params;
+use crate::utils::math;
+use crate::utils::parse::{self, Template};
+use crate::utils::{self, ParseError};
+
+pub const VERSION: &str = "4";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Button {
+    pub x: u64,
+    pub y: u64,
+    // tokens per press
+    pub cost: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Prize {
+    pub x: u64,
+    pub y: u64,
+}
+
+// buttons A, B, C, ... in order, at least A and B
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Game {
+    pub buttons: Vec<Button>,
+    pub prize: Prize,
+}
+
+// how many times buttons A and B have to be pressed to win the prize in a game with just those two
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Presses {
+    None,
+    Unique(u64, u64),
+    // both buttons move along the line to the prize and whole, non-negative presses reach it,
+    // possibly in more than one way
+    Collinear,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub games: Vec<Game>,
+}
+
+const MAX_BUTTONS: usize = 26;
+// how many press counts of the free buttons `Basis::search` may go through for one game
+const MAX_SEARCH: u64 = 10_000_000;
+// the longest step in games of more than two buttons, so that the determinant of any two of them
+// fits into the i64 the congruences are solved in
+const MAX_STEP: u64 = 3_037_000_499;
+
+fn button_name(index: usize) -> char {
+    (b'A' + index as u8) as char
+}
+
+// A costs 3 tokens and every other button 1, unless the line gives a cost
+fn default_cost(index: usize) -> u64 {
+    if index == 0 {
+        3
+    } else {
+        1
+    }
+}
+
+// `cost_a`, `cost_b`, `cost_c`, ... per button, for the buttons without a cost of their own
+fn param_cost(index: usize) -> u64 {
+    let name = format!("cost_{}", button_name(index).to_ascii_lowercase());
+    params::get(&name, default_cost(index))
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let button = Template::new("Button {}: X+{u64}, Y+{u64}");
+        let button_with_cost = Template::new("Button {}: X+{u64}, Y+{u64}, Cost={u64}");
+        let prize = Template::new("Prize: X={u64}, Y={u64}");
+        let parse_button = |line: &str, index: usize| -> Result<Button, ParseError> {
+            let (fields, cost) = match button_with_cost.captures(line) {
+                Ok(fields) => {
+                    let cost = fields.get(3)?;
+                    (fields, cost)
+                }
+                Err(_) => (button.captures(line)?, param_cost(index)),
+            };
+            let name = button_name(index);
+            if fields.text(0) != name.to_string() {
+                return Err(ParseError::new(format!(
+                    "Expected button {} in {:?}",
+                    name, line
+                )));
+            }
+            Ok(Button {
+                x: fields.get(1)?,
+                y: fields.get(2)?,
+                cost,
+            })
+        };
+
+        let mut games = Vec::new();
+        for block in parse::blocks(s) {
+            let lines: Vec<_> = block.lines().collect();
+            if !(3..=MAX_BUTTONS + 1).contains(&lines.len()) {
+                return Err(ParseError::new(format!(
+                    "Expected 2 to {} buttons and a prize in {:?}",
+                    MAX_BUTTONS, block
+                )));
+            }
+            let (prize_line, button_lines) = lines.split_last().unwrap();
+            let prize = prize.captures(prize_line)?;
+            let buttons: Vec<Button> = (button_lines.iter().enumerate())
+                .map(|(i, line)| parse_button(line, i))
+                .collect::<Result<_, _>>()?;
+            let longest = buttons.iter().map(|b| b.x.max(b.y)).max().unwrap_or(0);
+            if buttons.len() > 2 && longest > MAX_STEP {
+                return Err(ParseError::new(format!(
+                    "Expected steps up to {} with more than two buttons in {:?}",
+                    MAX_STEP, block
+                )));
+            }
+            if search_size(&buttons) > MAX_SEARCH {
+                return Err(ParseError::new(format!(
+                    "Too many buttons with too long steps to search in {:?}",
+                    block
+                )));
+            }
+            games.push(Game {
+                buttons,
+                prize: Prize {
+                    x: prize.get(0)?,
+                    y: prize.get(1)?,
+                },
+            });
+        }
+
+        Ok(Input { games })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, game) in self.games.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (j, button) in game.buttons.iter().enumerate() {
+                write!(
+                    f,
+                    "Button {}: X+{}, Y+{}",
+                    button_name(j),
+                    button.x,
+                    button.y
+                )?;
+                match button.cost == default_cost(j) {
+                    true => writeln!(f)?,
+                    false => writeln!(f, ", Cost={}", button.cost)?,
+                }
+            }
+            writeln!(f, "Prize: X={}, Y={}", game.prize.x, game.prize.y)?;
+        }
+        Ok(())
+    }
+}
+
+fn calibrate(game: &Game) -> Game {
+    let calibration: u64 = params::get("calibration", 10_000_000_000_000);
+    let mut calibrated_game = game.clone();
+    calibrated_game.prize.x += calibration;
+    calibrated_game.prize.y += calibration;
+    calibrated_game
+}
+
+fn token_costs(game: &Game) -> Vec<u64> {
+    game.buttons.iter().map(|button| button.cost).collect()
+}
+
+fn tokens(presses: &[u64], costs: &[u64]) -> u64 {
+    presses
+        .iter()
+        .zip(costs)
+        .map(|(presses, cost)| presses * cost)
+        .sum()
+}
+
+// the cheapest way of reaching the prize with up to `tries` presses per button
+fn find_solution(game: &Game, tries: u64, costs: &[u64]) -> Option<Vec<u64>> {
+    let mut best: Option<Vec<u64>> = None;
+    let mut presses = vec![0; game.buttons.len()];
+    loop {
+        let x = game
+            .buttons
+            .iter()
+            .zip(&presses)
+            .map(|(button, n)| button.x * n)
+            .sum();
+        let y = game
+            .buttons
+            .iter()
+            .zip(&presses)
+            .map(|(button, n)| button.y * n)
+            .sum();
+        if game.prize == (Prize { x, y })
+            && best
+                .as_ref()
+                .is_none_or(|best| tokens(&presses, costs) < tokens(best, costs))
+        {
+            best = Some(presses.clone());
+        }
+
+        // counts up like an odometer, with the first button turning fastest
+        match presses.iter().position(|n| *n + 1 < tries) {
+            Some(i) => {
+                presses[i] += 1;
+                presses[..i].fill(0);
+            }
+            None => break,
+        }
+    }
+    best
+}
+
+pub fn part_1(input: &Input) -> u64 {
+    let mut res = 0;
+
+    for game in &input.games {
+        let costs = token_costs(game);
+        if let Some(presses) = cheapest(game, &costs) {
+            res += tokens(&presses, &costs);
+        }
+    }
+
+    res
+}
+
+// tries every combination of up to 100 presses per button, which gets slow beyond three buttons
+pub fn part_1_brute_force(input: &Input) -> u64 {
+    let mut res = 0;
+
+    for game in &input.games {
+        let costs = token_costs(game);
+        if let Some(presses) = find_solution(game, 100, &costs) {
+            res += tokens(&presses, &costs);
+        }
+    }
+
+    res
+}
+
+// solves a * button_a + b * button_b = prize with Cramer's rule, in integers so nothing is rounded
+pub fn solve(button_a: &Button, button_b: &Button, prize: Prize) -> Presses {
+    let (ax, ay) = (button_a.x as i128, button_a.y as i128);
+    let (bx, by) = (button_b.x as i128, button_b.y as i128);
+    let (px, py) = (prize.x as i128, prize.y as i128);
+
+    let determinant = ax * by - ay * bx;
+    if determinant == 0 {
+        // parallel buttons only reach prizes on their line, and even there the steps may not
+        // add up to the distance without pressing a button a negative number of times
+        let on_line = match (ax, ay, bx, by) {
+            (0, 0, 0, 0) => px == 0 && py == 0,
+            (0, 0, x, y) | (x, y, _, _) => x * py == y * px,
+        };
+        return match on_line && cheapest_collinear(button_a, button_b, prize, 1, 1).is_some() {
+            true => Presses::Collinear,
+            false => Presses::None,
+        };
+    }
+
+    let a = px * by - py * bx;
+    let b = ax * py - ay * px;
+    if a % determinant != 0 || b % determinant != 0 {
+        return Presses::None;
+    }
+    match (a / determinant, b / determinant) {
+        (a, b) if a >= 0 && b >= 0 => Presses::Unique(a as u64, b as u64),
+        _ => Presses::None,
+    }
+}
+
+// the presses of every button winning the prize for the fewest tokens, when it can be won
+pub fn cheapest(game: &Game, costs: &[u64]) -> Option<Vec<u64>> {
+    if let [button_a, button_b] = game.buttons.as_slice() {
+        let (a, b) = cheapest_pair(button_a, button_b, game.prize, costs[0], costs[1])?;
+        return Some(vec![a, b]);
+    }
+    if game.prize == (Prize { x: 0, y: 0 }) {
+        return Some(vec![0; game.buttons.len()]);
+    }
+    Basis::cheapest(game, costs)?.search(game, costs)
+}
+
+// Buttons whose presses follow from how far the other buttons leave the claw from the prize:
+// with `r` left to go, `buttons[i]` is pressed `(rows[i].0 * r.x + rows[i].1 * r.y) / divisor`
+// times. Two buttons moving in different directions, or one when every button moves along the
+// same line.
+struct Basis {
+    buttons: Vec<usize>,
+    rows: Vec<(i128, i128)>,
+    divisor: i128,
+}
+
+fn vector(button: &Button) -> (i128, i128) {
+    (button.x as i128, button.y as i128)
+}
+
+fn vector_to(prize: Prize) -> (i128, i128) {
+    (prize.x as i128, prize.y as i128)
+}
+
+// m * (2 * m * d + 1)^m for m basic buttons and d the longest step of any button, the presses
+// of the other buttons there is a cheapest win within (Eisenbrand and Weismantel, proximity for
+// integer programs), or u64::MAX when that does not fit
+fn budget(m: u64, buttons: &[Button]) -> u64 {
+    let d = (buttons.iter())
+        .map(|button| button.x.max(button.y))
+        .max()
+        .unwrap_or(0);
+    (d.checked_mul(2 * m))
+        .and_then(|steps| steps.checked_add(1))
+        .and_then(|steps| steps.checked_pow(m as u32))
+        .and_then(|steps| steps.checked_mul(m))
+        .unwrap_or(u64::MAX)
+}
+
+// How many press counts `Basis::search` goes through at most: the moving buttons beyond the
+// basis and the one `settle` solves for share the budget, which they can split in
+// (budget + k choose k) ways for k of them. Two buttons are solved directly.
+fn search_size(buttons: &[Button]) -> u64 {
+    let moving: Vec<&Button> = (buttons.iter())
+        .filter(|button| button.x != 0 || button.y != 0)
+        .collect();
+    if buttons.len() <= 2 || moving.is_empty() {
+        return 1;
+    }
+    let independent = moving.iter().enumerate().any(|(i, a)| {
+        (moving[i + 1..].iter()).any(|b| a.x as u128 * b.y as u128 != a.y as u128 * b.x as u128)
+    });
+    let m = if independent { 2 } else { 1 };
+    let k = moving.len().saturating_sub(m + 1) as u64;
+    let budget = budget(m as u64, buttons) as u128;
+
+    let mut size: u128 = 1;
+    for i in 1..=k as u128 {
+        match size.checked_mul(budget + i) {
+            Some(product) => size = product / i,
+            None => return u64::MAX,
+        }
+    }
+    size.try_into().unwrap_or(u64::MAX)
+}
+
+impl Basis {
+    // the numerators of the basic presses for `(x, y)` left to go, or of how much pressing
+    // another button once takes off them
+    fn numerators(&self, (x, y): (i128, i128)) -> Vec<i128> {
+        self.rows.iter().map(|(rx, ry)| rx * x + ry * y).collect()
+    }
+
+    // Cramer's rule, with the signs flipped so the divisor is positive
+    fn pair(game: &Game, i: usize, j: usize) -> Option<Basis> {
+        let ((ax, ay), (bx, by)) = (vector(&game.buttons[i]), vector(&game.buttons[j]));
+        let sign = match (ax * by - ay * bx).signum() {
+            0 => return None,
+            sign => sign,
+        };
+        Some(Basis {
+            buttons: vec![i, j],
+            rows: vec![(sign * by, -sign * bx), (-sign * ay, sign * ax)],
+            divisor: sign * (ax * by - ay * bx),
+        })
+    }
+
+    // The basis of the cheapest corner of the relaxed problem, where presses may be fractions.
+    // Those corners press at most two buttons, and when no two buttons point different ways the
+    // cheapest is the button with the most distance per token.
+    fn cheapest(game: &Game, costs: &[u64]) -> Option<Basis> {
+        let prize = (game.prize.x as i128, game.prize.y as i128);
+        let count = game.buttons.len();
+        let pairs: Vec<Basis> = (0..count)
+            .flat_map(|i| (i + 1..count).filter_map(move |j| Basis::pair(game, i, j)))
+            .collect();
+
+        if !pairs.is_empty() {
+            // cost * divisor of every corner that is reachable with non-negative presses
+            let corners = pairs.into_iter().filter_map(|basis| {
+                let numerators = basis.numerators(prize);
+                if numerators.iter().any(|numerator| *numerator < 0) {
+                    return None;
+                }
+                let cost: i128 = (basis.buttons.iter().zip(&numerators))
+                    .map(|(button, numerator)| costs[*button] as i128 * numerator)
+                    .sum();
+                Some((cost, basis))
+            });
+            return corners
+                .min_by(|(a, a_basis), (b, b_basis)| {
+                    (a * b_basis.divisor).cmp(&(b * a_basis.divisor))
+                })
+                .map(|(_, basis)| basis);
+        }
+
+        // every button moves along one line, which has to go through the prize, and matching
+        // one coordinate then matches both
+        let moving = game.buttons.iter().find(|b| b.x != 0 || b.y != 0)?;
+        let (dx, dy) = vector(moving);
+        if dx * prize.1 != dy * prize.0 {
+            return None;
+        }
+        let along_x = game.buttons.iter().any(|b| b.x != 0);
+        let step = |i: usize| match along_x {
+            true => game.buttons[i].x as i128,
+            false => game.buttons[i].y as i128,
+        };
+        let best = (0..count)
+            .filter(|i| step(*i) != 0)
+            .min_by(|i, j| (costs[*i] as i128 * step(*j)).cmp(&(costs[*j] as i128 * step(*i))))?;
+        Some(Basis {
+            buttons: vec![best],
+            rows: vec![if along_x { (1, 0) } else { (0, 1) }],
+            divisor: step(best),
+        })
+    }
+
+    // There is a cheapest win within `budget` presses of the cheapest relaxed corner, which
+    // presses no other button, so that bounds how often they are pressed in total. Only the free
+    // buttons but the last are tried one press count at a time, which `search_size` keeps
+    // within `MAX_SEARCH` for the games that parse.
+    fn search(&self, game: &Game, costs: &[u64]) -> Option<Vec<u64>> {
+        let budget = budget(self.buttons.len() as u64, &game.buttons);
+
+        // a button that does not move is never worth pressing
+        let free: Vec<usize> = (0..game.buttons.len())
+            .filter(|i| !self.buttons.contains(i))
+            .filter(|i| game.buttons[*i].x != 0 || game.buttons[*i].y != 0)
+            .collect();
+        let mut search = BoundedSearch {
+            basis: self,
+            game,
+            costs,
+            presses: vec![0; game.buttons.len()],
+            best: None,
+        };
+        search.press_free(&free, budget, vector_to(game.prize), 0);
+        search.best.map(|(_, presses)| presses)
+    }
+}
+
+// the presses tried so far and the cheapest win with its cost
+struct BoundedSearch<'a> {
+    basis: &'a Basis,
+    game: &'a Game,
+    costs: &'a [u64],
+    presses: Vec<u64>,
+    best: Option<(i128, Vec<u64>)>,
+}
+
+impl BoundedSearch<'_> {
+    // tries every number of presses of the free buttons but the last, which `settle` solves for,
+    // with `left` to go to the prize
+    fn press_free(&mut self, free: &[usize], budget: u64, left: (i128, i128), spent: i128) {
+        let (first, rest) = match free {
+            [first, rest @ ..] if !rest.is_empty() => (*first, rest),
+            _ => {
+                let numerators = self.basis.numerators(left);
+                return self.settle(free.first().copied(), budget, numerators, spent);
+            }
+        };
+
+        let (dx, dy) = vector(&self.game.buttons[first]);
+        let mut left = left;
+        for n in 0..=budget {
+            // no button moves back, so there is no return from going past the prize
+            if left.0 < 0 || left.1 < 0 {
+                break;
+            }
+            self.presses[first] = n;
+            let cost = spent + self.costs[first] as i128 * n as i128;
+            self.press_free(rest, budget - n, left, cost);
+            left = (left.0 - dx, left.1 - dy);
+        }
+        self.presses[first] = 0;
+    }
+
+    // The cheapest number of presses of the `last` free button, up to `budget`, that leaves
+    // whole non-negative presses for the basic buttons. The whole ones repeat with a period
+    // found from the congruences, the non-negative ones form a range, and the cost changes by
+    // the same amount with every press, so the cheapest is at one end of the range.
+    fn settle(&mut self, last: Option<usize>, budget: u64, numerators: Vec<i128>, spent: i128) {
+        let basis = self.basis;
+        let (steps, cost, budget) = match last {
+            Some(last) => (
+                basis.numerators(vector(&self.game.buttons[last])),
+                self.costs[last] as i128,
+                budget as i128,
+            ),
+            None => (vec![0; basis.buttons.len()], 0, 0),
+        };
+        let divisor = basis.divisor;
+        // `MAX_STEP` keeps the divisor within i64 for the games that parse, the others are not
+        // searched
+        let Ok(modulus) = i64::try_from(divisor) else {
+            return;
+        };
+
+        // n * step = numerator (mod divisor) for every basic button
+        let mut congruences = Vec::new();
+        for (numerator, step) in numerators.iter().zip(&steps) {
+            // both are below the divisor now, so they fit too
+            let (step, numerator) = (step.rem_euclid(divisor), numerator.rem_euclid(divisor));
+            match math::solve_congruence(step as i64, numerator as i64, modulus) {
+                Some(congruence) => congruences.push(congruence),
+                None => return,
+            }
+        }
+        let Some((residue, period)) = math::crt(&congruences) else {
+            return;
+        };
+        let (residue, period) = (residue as i128, period as i128);
+
+        // n * step <= numerator for every basic button
+        let (mut low, mut high) = (0, budget);
+        for (numerator, step) in numerators.iter().zip(&steps) {
+            match step.signum() {
+                1 => high = high.min(numerator.div_euclid(*step)),
+                -1 => low = low.max(-(numerator.div_euclid(-step))),
+                _ if *numerator < 0 => return,
+                _ => {}
+            }
+        }
+
+        let basic_costs: Vec<i128> = (basis.buttons.iter())
+            .map(|button| self.costs[*button] as i128)
+            .collect();
+        let slope = cost * divisor
+            - (basic_costs.iter().zip(&steps))
+                .map(|(cost, step)| cost * step)
+                .sum::<i128>();
+        let n = match slope >= 0 {
+            true => low + (residue - low).rem_euclid(period),
+            false => high - (high - residue).rem_euclid(period),
+        };
+        if n < low || n > high {
+            return;
+        }
+
+        let basic: Vec<i128> = (numerators.iter().zip(&steps))
+            .map(|(numerator, step)| (numerator - n * step) / divisor)
+            .collect();
+        let total = spent
+            + cost * n
+            + (basic_costs.iter().zip(&basic))
+                .map(|(cost, presses)| cost * presses)
+                .sum::<i128>();
+        if self.best.as_ref().is_some_and(|(best, _)| *best <= total) {
+            return;
+        }
+        if let Some(last) = last {
+            self.presses[last] = n as u64;
+        }
+        for (button, count) in basis.buttons.iter().zip(&basic) {
+            self.presses[*button] = *count as u64;
+        }
+        self.best = Some((total, self.presses.clone()));
+        if let Some(last) = last {
+            self.presses[last] = 0;
+        }
+    }
+}
+
+// the cheapest presses of two buttons winning the prize
+fn cheapest_pair(
+    button_a: &Button,
+    button_b: &Button,
+    prize: Prize,
+    cost_a: u64,
+    cost_b: u64,
+) -> Option<(u64, u64)> {
+    match solve(button_a, button_b, prize) {
+        Presses::None => None,
+        Presses::Unique(a, b) => Some((a, b)),
+        Presses::Collinear => {
+            cheapest_collinear(button_a, button_b, prize, cost_a as i128, cost_b as i128)
+        }
+    }
+}
+
+// Every button moves along the prize's line, so matching one coordinate matches both. That leaves
+// a * u + b * v = t, whose solutions are a0 + k * v / g and b0 - k * u / g for g = gcd(u, v), and
+// the cost changes by the same amount with every step of k, so the cheapest is at one of the ends.
+fn cheapest_collinear(
+    button_a: &Button,
+    button_b: &Button,
+    prize: Prize,
+    cost_a: i128,
+    cost_b: i128,
+) -> Option<(u64, u64)> {
+    let (u, v, t) = match button_a.x != 0 || button_b.x != 0 {
+        true => (button_a.x, button_b.x, prize.x),
+        false => (button_a.y, button_b.y, prize.y),
+    };
+    let (u, v, t) = (u as i128, v as i128, t as i128);
+
+    // a button that does not move is never worth pressing
+    match (u, v) {
+        (0, 0) => return (t == 0).then_some((0, 0)),
+        (0, v) => return (t % v == 0).then(|| (0, (t / v) as u64)),
+        (u, 0) => return (t % u == 0).then(|| ((t / u) as u64, 0)),
+        _ => {}
+    }
+
+    let (g, x, y) = math::extended_gcd(u, v);
+    if t % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x * (t / g), y * (t / g));
+    let (step_a, step_b) = (v / g, u / g);
+    // a0 + k * step_a >= 0 and b0 - k * step_b >= 0
+    let k_min = (-a0).div_euclid(step_a) + ((-a0).rem_euclid(step_a) != 0) as i128;
+    let k_max = b0.div_euclid(step_b);
+    if k_min > k_max {
+        return None;
+    }
+
+    let k = match cost_a * step_a - cost_b * step_b >= 0 {
+        true => k_min,
+        false => k_max,
+    };
+    Some(((a0 + k * step_a) as u64, (b0 - k * step_b) as u64))
+}
+
+pub fn part_2(input: &Input) -> u64 {
+    let mut res = 0;
+
+    let games: Vec<_> = input.games.iter().map(calibrate).collect();
+    for game in &games {
+        let costs = token_costs(game);
+        if let Some(presses) = cheapest(game, &costs) {
+            res += tokens(&presses, &costs);
+        }
+    }
+
+    res
+}
+
+pub fn solve_part_1(filepath: &str) -> u64 {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> u64 {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_13_test"), 480);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_13_test"), 875318608908);
+    }
+
+    #[test]
+    fn test_brute_force_part1() {
+        let input: Input = utils::load_input("inputs/2024/input_13_test");
+        assert_eq!(part_1_brute_force(&input), part_1(&input));
+    }
+
+    fn game(button_a: (u64, u64), button_b: (u64, u64), prize: (u64, u64)) -> Game {
+        game_with(&[button_a, button_b], prize)
+    }
+
+    fn game_with(buttons: &[(u64, u64)], (x, y): (u64, u64)) -> Game {
+        Game {
+            buttons: (buttons.iter().enumerate())
+                .map(|(i, &(x, y))| Button {
+                    x,
+                    y,
+                    cost: default_cost(i),
+                })
+                .collect(),
+            prize: Prize { x, y },
+        }
+    }
+
+    fn solve_game(game: &Game) -> Presses {
+        solve(&game.buttons[0], &game.buttons[1], game.prize)
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve_game(&game((94, 34), (22, 67), (8400, 5400))),
+            Presses::Unique(80, 40)
+        );
+        // not a whole number of presses
+        assert_eq!(
+            solve_game(&game((26, 66), (67, 21), (12748, 12176))),
+            Presses::None
+        );
+        assert_eq!(
+            solve_game(&game((1, 0), (0, 1), (5, 0))),
+            Presses::Unique(5, 0)
+        );
+        // would need to press A a negative number of times
+        assert_eq!(solve_game(&game((2, 1), (1, 2), (1, 5))), Presses::None);
+        // a button without any X movement
+        assert_eq!(
+            solve_game(&game((0, 3), (2, 1), (4, 8))),
+            Presses::Unique(2, 2)
+        );
+        // big enough to lose precision in f64
+        let calibrated = game((94, 34), (22, 67), (10_000_000_008_400, 10_000_000_005_400));
+        assert_eq!(solve_game(&calibrated), Presses::None);
+        let exact = game(
+            (3, 7),
+            (5, 2),
+            (3 * 999_999_999_999 + 5, 7 * 999_999_999_999 + 2),
+        );
+        assert_eq!(solve_game(&exact), Presses::Unique(999_999_999_999, 1));
+
+        assert_eq!(
+            solve_game(&game((1, 2), (2, 4), (3, 6))),
+            Presses::Collinear
+        );
+        assert_eq!(solve_game(&game((1, 2), (2, 4), (3, 7))), Presses::None);
+        assert_eq!(
+            solve_game(&game((0, 0), (2, 4), (6, 12))),
+            Presses::Collinear
+        );
+        assert_eq!(solve_game(&game((0, 0), (0, 0), (3, 6))), Presses::None);
+        assert_eq!(
+            solve_game(&game((0, 0), (0, 0), (0, 0))),
+            Presses::Collinear
+        );
+        // on the line, but every press moves an even distance
+        assert_eq!(solve_game(&game((2, 4), (4, 8), (3, 6))), Presses::None);
+        assert_eq!(solve_game(&game((0, 0), (2, 4), (3, 6))), Presses::None);
+        // on the line and reachable in integers, but only by pressing A -1 times
+        assert_eq!(solve_game(&game((4, 2), (6, 3), (2, 1))), Presses::None);
+    }
+
+    #[test]
+    fn test_cheapest_collinear() {
+        // A goes as far as two B presses, so it is only worth pressing when it costs less than two
+        let line = game((2, 4), (1, 2), (7, 14));
+        assert_eq!(solve_game(&line), Presses::Collinear);
+        assert_eq!(cheapest(&line, &[3, 1]), Some(vec![0, 7]));
+        assert_eq!(cheapest(&line, &[1, 1]), Some(vec![3, 1]));
+        assert_eq!(cheapest(&line, &[2, 1]), Some(vec![0, 7]));
+
+        // the gcd of the steps does not divide the distance
+        assert_eq!(cheapest(&game((4, 2), (6, 3), (9, 3)), &[3, 1]), None);
+        assert_eq!(
+            cheapest(&game((4, 2), (6, 3), (10, 5)), &[3, 1]),
+            Some(vec![1, 1])
+        );
+        // reachable in integers, but only with negative presses
+        assert_eq!(cheapest(&game((4, 2), (6, 3), (2, 1)), &[3, 1]), None);
+        // buttons only moving along Y, and buttons not moving at all
+        assert_eq!(
+            cheapest(&game((0, 3), (0, 5), (0, 13)), &[1, 1]),
+            Some(vec![1, 2])
+        );
+        assert_eq!(
+            cheapest(&game((0, 0), (2, 4), (6, 12)), &[3, 1]),
+            Some(vec![0, 3])
+        );
+        assert_eq!(
+            cheapest(&game((0, 0), (0, 0), (0, 0)), &[3, 1]),
+            Some(vec![0, 0])
+        );
+
+        for costs in [[3, 1], [1, 1], [1, 3], [5, 2]] {
+            for prize in 0..60 {
+                let game = game((3, 6), (5, 10), (prize, 2 * prize));
+                assert_eq!(
+                    cheapest(&game, &costs).map(|presses| tokens(&presses, &costs)),
+                    find_solution(&game, 100, &costs).map(|presses| tokens(&presses, &costs))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_token_costs() {
+        // the params only set the costs of buttons read while they apply
+        let mut same_cost = params::Params::new();
+        same_cost.set("cost_a", "1");
+        same_cost.set("cost_b", "1");
+        let input: Input = params::with_params(&same_cost, || {
+            utils::load_input("inputs/2024/input_13_test")
+        });
+        assert_eq!(part_1(&input), 80 + 40 + 38 + 86);
+
+        // a cost on the button line wins over the params
+        let input: Input = "Button A: X+1, Y+0, Cost=2\nButton B: X+2, Y+0\nPrize: X=4, Y=0"
+            .parse()
+            .unwrap();
+        assert_eq!(params::with_params(&same_cost, || part_1(&input)), 2);
+        let input = input.to_string();
+        assert!(input.contains("Cost=2"));
+        let input: Input = params::with_params(&same_cost, || input.parse().unwrap());
+        assert_eq!(token_costs(&input.games[0]), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_more_buttons() {
+        let input: Input = utils::load_input("inputs/2024/input_13_test_2");
+        let costs: Vec<_> = input.games.iter().map(token_costs).collect();
+        assert_eq!(costs[2], vec![3, 1, 1, 1]);
+        let presses: Vec<_> = input
+            .games
+            .iter()
+            .map(|game| cheapest(game, &token_costs(game)))
+            .collect();
+        assert_eq!(
+            presses,
+            vec![
+                Some(vec![80, 40, 0]),
+                Some(vec![0, 0, 20]),
+                Some(vec![1, 1, 0, 6]),
+                None
+            ]
+        );
+        assert_eq!(part_1(&input), 280 + 20 + 10);
+
+        let mut cheap_c = params::Params::new();
+        cheap_c.set("cost_c", "0");
+        let with_cheap_c: Input = params::with_params(&cheap_c, || {
+            utils::load_input("inputs/2024/input_13_test_2")
+        });
+        assert_eq!(part_1(&with_cheap_c), 150 + 2);
+
+        // three buttons are still quick to brute force
+        let three_buttons: Vec<_> = input
+            .games
+            .into_iter()
+            .filter(|game| game.buttons.len() == 3)
+            .collect();
+        for game in &three_buttons {
+            let costs = token_costs(game);
+            assert_eq!(
+                cheapest(game, &costs).map(|presses| tokens(&presses, &costs)),
+                find_solution(game, 100, &costs).map(|presses| tokens(&presses, &costs))
+            );
+        }
+    }
+
+    #[test]
+    fn test_more_buttons_part2() {
+        let input: Input = utils::load_input("inputs/2024/input_13_test_2");
+        let calibrated: Vec<_> = input.games.iter().map(calibrate).collect();
+        let costs: Vec<_> = calibrated
+            .iter()
+            .map(|game| cheapest(game, &token_costs(game)).map(|p| tokens(&p, &token_costs(game))))
+            .collect();
+        assert_eq!(
+            costs,
+            vec![
+                Some(351351351632),
+                Some(5000000000020),
+                Some(5333333333342),
+                None
+            ]
+        );
+        assert_eq!(part_2(&input), 10684684684994);
+    }
+
+    #[test]
+    fn test_cheapest_matches_brute_force() {
+        let button_sets: [&[(u64, u64)]; 6] = [
+            &[(3, 1), (1, 3), (2, 2)],
+            &[(2, 1), (4, 2), (6, 3)],
+            &[(0, 0), (1, 2), (3, 1)],
+            &[(5, 0), (0, 4), (3, 3)],
+            &[(2, 3), (3, 2), (1, 1), (4, 1)],
+            &[(1, 2), (2, 4), (0, 0), (3, 6)],
+        ];
+        for buttons in button_sets {
+            let size = if buttons.len() == 3 { 12 } else { 6 };
+            for costs in [[3, 1, 1, 1], [1, 2, 5, 1], [1, 1, 0, 2]] {
+                let costs = &costs[..buttons.len()];
+                for x in 0..size {
+                    for y in 0..size {
+                        let game = game_with(buttons, (x, y));
+                        // no button moves less than 1, so nothing is pressed more than size times
+                        assert_eq!(
+                            cheapest(&game, costs).map(|presses| tokens(&presses, costs)),
+                            find_solution(&game, size + 1, costs)
+                                .map(|presses| tokens(&presses, costs)),
+                            "{:?} with costs {:?}",
+                            game,
+                            costs
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_limits() {
+        // the budget does not fit into u64
+        let long = 1 << 40;
+        let game = game_with(
+            &[(long, long), (long, long + 1), (1, 1)],
+            (long + 1, long + 2),
+        );
+        assert_eq!(budget(2, &game.buttons), u64::MAX);
+        assert_eq!(cheapest(&game, &[3, 1, 1]), Some(vec![0, 1, 1]));
+
+        // five short buttons are fine, five long ones would take too long to search
+        let short = game_with(&[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)], (5, 7));
+        assert!(search_size(&short.buttons) <= MAX_SEARCH);
+        let costs = token_costs(&short);
+        assert_eq!(
+            cheapest(&short, &costs).map(|presses| tokens(&presses, &costs)),
+            find_solution(&short, 13, &costs).map(|presses| tokens(&presses, &costs))
+        );
+        let buttons: String = ["A", "B", "C", "D", "E"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| format!("Button {}: X+{}, Y+{}\n", name, 90 + i, 99 - i))
+            .collect();
+        assert!((buttons + "Prize: X=1000, Y=1000")
+            .parse::<Input>()
+            .is_err());
+
+        // the determinant of A and B would not fit into i64
+        let steps = |step: u64| {
+            format!(
+                "Button A: X+{}, Y+0\nButton B: X+0, Y+{}\nButton C: X+1, Y+1\nPrize: X=1, Y=1",
+                step, step
+            )
+        };
+        let input: Input = steps(MAX_STEP).parse().unwrap();
+        assert_eq!(cheapest(&input.games[0], &[3, 1, 1]), Some(vec![0, 0, 1]));
+        assert!(steps(MAX_STEP + 1).parse::<Input>().is_err());
+        // only the congruences need it to, two buttons are solved in i128
+        assert!(format!(
+            "Button A: X+{}, Y+0\nButton B: X+0, Y+1\nPrize: X=1, Y=1",
+            u64::MAX
+        )
+        .parse::<Input>()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_parse_buttons() {
+        assert!("Button A: X+1, Y+2\nPrize: X=1, Y=2"
+            .parse::<Input>()
+            .is_err());
+        assert!("Button A: X+1, Y+2\nButton C: X+1, Y+2\nPrize: X=1, Y=2"
+            .parse::<Input>()
+            .is_err());
+        let input: Input =
+            "Button A: X+1, Y+2\nButton B: X+3, Y+4\nButton C: X+5, Y+6\nPrize: X=1, Y=2"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            input.games[0].buttons[2],
+            Button {
+                x: 5,
+                y: 6,
+                cost: 1
+            }
+        );
+
+        let input: Input = "Button A: X+1, Y+2, Cost=7\nButton B: X+3, Y+4\nPrize: X=1, Y=2"
+            .parse()
+            .unwrap();
+        assert_eq!(token_costs(&input.games[0]), vec![7, 1]);
+        assert!(
+            "Button A: X+1, Y+2, Cost=-1\nButton B: X+3, Y+4\nPrize: X=1, Y=2"
+                .parse::<Input>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        for path in ["inputs/2024/input_13_test", "inputs/2024/input_13_test_2"] {
+            let input: Input = utils::load_input(path);
+            assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+        }
+    }
+}
@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint;
+use crate::params;
+use crate::repl;
+use crate::trace;
+use crate::utils::parse::{self, Template};
+use crate::utils::{self, math, ParseError, Point, Vec2};
+
+pub const VERSION: &str = "2";
+
+pub const MAP_HEIGHT: i64 = 103;
+pub const MAP_WIDTH: i64 = 101;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Robot {
+    pub p: Point,
+    pub v: Vec2,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Quadrants {
+    q1: u64,
+    q2: u64,
+    q3: u64,
+    q4: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub robots: Vec<Robot>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut robots = Vec::new();
+
+        let robot = Template::new("p={i64},{i64} v={i64},{i64}");
+        for line in parse::lines(s) {
+            let fields = robot.captures(line)?;
+            robots.push(Robot {
+                p: Point::new(fields.get(0)?, fields.get(1)?),
+                v: Vec2::new(fields.get(2)?, fields.get(3)?),
+            });
+        }
+
+        Ok(Input { robots })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for robot in &self.robots {
+            writeln!(f, "p={} v={}", robot.p, robot.v)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn print_robots(robots: &[Robot], map_height: i64, map_width: i64) {
+    let mut robots_map: HashMap<Point, u64> = HashMap::new();
+
+    for i in 0..map_width {
+        for j in 0..map_height {
+            robots_map.insert(Point::new(i, j), 0);
+        }
+    }
+    for robot in robots {
+        *robots_map.entry(robot.p).or_insert(0) += 1;
+    }
+
+    for j in 0..map_height {
+        for i in 0..map_width {
+            if let Some(val) = robots_map.get(&Point::new(i, j)) {
+                if *val == 0 {
+                    print!(".");
+                } else {
+                    print!("{}", val);
+                }
+            }
+        }
+        println!();
+    }
+}
+
+pub fn run_simulation(robots: &[Robot], map_height: i64, map_width: i64) -> Vec<Robot> {
+    let _span = trace::span("run_simulation");
+    let mut new_robots_positions = Vec::new();
+
+    for robot in robots {
+        let mut new_robot = *robot;
+        new_robot.p += new_robot.v;
+        if new_robot.p.x < 0 {
+            new_robot.p.x += map_width;
+        }
+        if new_robot.p.x >= map_width {
+            new_robot.p.x -= map_width;
+        }
+        if new_robot.p.y < 0 {
+            new_robot.p.y += map_height;
+        }
+        if new_robot.p.y >= map_height {
+            new_robot.p.y -= map_height;
+        }
+        new_robots_positions.push(new_robot);
+    }
+
+    new_robots_positions
+}
+
+// where the robot is after `seconds` seconds, without going through the seconds in between
+pub fn position_at(robot: &Robot, seconds: u64, map_height: i64, map_width: i64) -> Point {
+    // the map wraps around, so only the seconds within one lap of each axis matter
+    let x_seconds = (seconds % map_width as u64) as i64;
+    let y_seconds = (seconds % map_height as u64) as i64;
+    Point::new(
+        (robot.p.x + robot.v.x * x_seconds).rem_euclid(map_width),
+        (robot.p.y + robot.v.y * y_seconds).rem_euclid(map_height),
+    )
+}
+
+fn robots_per_quardant(robots: &[Robot], map_height: i64, map_width: i64) -> Quadrants {
+    let mut quadrants = Quadrants {
+        q1: 0,
+        q2: 0,
+        q3: 0,
+        q4: 0,
+    };
+
+    let half_height = map_height / 2;
+    let half_width = map_width / 2;
+
+    for robot in robots {
+        let Point {
+            x: robot_x,
+            y: robot_y,
+        } = robot.p;
+        if robot_x < half_width && robot_y < half_height {
+            quadrants.q1 += 1;
+        }
+        if robot_x < half_width && robot_y > half_height {
+            quadrants.q2 += 1;
+        }
+        if robot_y < half_height && robot_x > half_width {
+            quadrants.q3 += 1;
+        }
+        if robot_x > half_width && robot_y > half_height {
+            quadrants.q4 += 1;
+        }
+    }
+
+    quadrants
+}
+
+pub fn part_1(input: &Input) -> io::Result<u64> {
+    let seconds = params::get("seconds", 100);
+    let mut simulation = Simulation::start(input)?;
+    if simulation.seconds > seconds {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Checkpoint after {} seconds is past {} seconds",
+                simulation.seconds, seconds
+            ),
+        ));
+    }
+
+    simulation.advance(seconds - simulation.seconds);
+    Ok(simulation.safety_factor())
+}
+
+fn find_christmas_tree(robots: &[Robot]) -> bool {
+    let _span = trace::span("find_christmas_tree");
+    let mut robots_map: HashMap<Point, u64> = HashMap::new();
+
+    for robot in robots {
+        *robots_map.entry(robot.p).or_insert(0) += 1;
+    }
+
+    *robots_map.values().max().unwrap() == 1
+}
+
+// after how many seconds the robot is back where it started: each axis repeats once the robot
+// has moved a multiple of the map size along it
+pub fn count_cycles(robot: &Robot, map_height: i64, map_width: i64) -> u64 {
+    let axis_cycle = |v: i64, size: i64| {
+        let size = size as u64;
+        size / math::gcd(v.rem_euclid(size as i64) as u64, size)
+    };
+    math::lcm(
+        axis_cycle(robot.v.x, map_width),
+        axis_cycle(robot.v.y, map_height),
+    )
+}
+
+pub fn part_2(input: &Input) -> io::Result<u64> {
+    let mut simulation = Simulation::start(input)?;
+
+    // finding how many positions every robot can have (to know what's the max)
+    // let cycles: HashMap<Point, u64> = robots
+    //     .iter()
+    //     .map(|r| (r.p, count_cycles(r, map_height, map_width)))
+    //     .collect();
+    //
+    // println!("Cycles {:?}", cycles);
+    // for (start_pos, c) in &cycles {
+    //     if *c != 10403 {
+    //         println!("{:?}, {}", start_pos, c);
+    //     }
+    // }
+
+    while !find_christmas_tree(&simulation.robots) {
+        simulation.step();
+    }
+
+    Ok(simulation.seconds)
+}
+
+// when the robots line up into a tree, and how sure the detector is about it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TreeSighting {
+    pub seconds: u64,
+    // 0 when the tightest frame is no tighter than an average one, up to 1 when all robots meet
+    pub confidence: f64,
+}
+
+fn variance(values: impl Iterator<Item = i64> + Clone) -> f64 {
+    let count = values.clone().count() as f64;
+    let mean = values.clone().sum::<i64>() as f64 / count;
+    values
+        .map(|value| (value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count
+}
+
+// how spread out the robots are along one axis at every second of its period
+fn axis_variances(robots: &[Robot], size: i64, axis: impl Fn(Point) -> i64) -> Vec<f64> {
+    (0..size)
+        .map(|seconds| {
+            variance(
+                robots
+                    .iter()
+                    .map(|robot| (axis(robot.p) + axis(robot.v) * seconds).rem_euclid(size)),
+            )
+        })
+        .collect()
+}
+
+// Each axis repeats every width or height seconds, so the tree frame is where the robots bunch
+// up the most along x within one width period and along y within one height period, and the CRT
+// turns the two into a single time. When the sizes share a factor, only phases agreeing modulo
+// it can be combined, so the best agreeing pair is used.
+pub fn find_tree_by_variance(
+    robots: &[Robot],
+    map_height: i64,
+    map_width: i64,
+) -> Option<TreeSighting> {
+    let _span = trace::span("find_tree_by_variance");
+    if robots.is_empty() {
+        return None;
+    }
+    let x_variances = axis_variances(robots, map_width, |p| p.x);
+    let y_variances = axis_variances(robots, map_height, |p| p.y);
+
+    // how far below the average variance a phase is
+    let score = |variances: &[f64], phase: usize| {
+        let mean = variances.iter().sum::<f64>() / variances.len() as f64;
+        match mean > 0.0 {
+            true => 1.0 - variances[phase] / mean,
+            false => 0.0,
+        }
+    };
+    let tightest = |variances: &[f64], residue: usize, shared: usize| {
+        (residue..variances.len())
+            .step_by(shared)
+            .min_by(|a, b| variances[*a].total_cmp(&variances[*b]))
+    };
+
+    let shared = math::gcd(map_width as u64, map_height as u64) as usize;
+    let (x_phase, y_phase, confidence) = (0..shared)
+        .filter_map(|residue| {
+            let x_phase = tightest(&x_variances, residue, shared)?;
+            let y_phase = tightest(&y_variances, residue, shared)?;
+            let confidence = score(&x_variances, x_phase).min(score(&y_variances, y_phase));
+            Some((x_phase, y_phase, confidence))
+        })
+        .max_by(|a, b| a.2.total_cmp(&b.2))?;
+
+    let (seconds, _) = math::crt(&[(x_phase as i64, map_width), (y_phase as i64, map_height)])?;
+    Some(TreeSighting {
+        seconds: seconds as u64,
+        confidence: confidence.clamp(0.0, 1.0),
+    })
+}
+
+// Finds the tree from how tightly the robots bunch up instead of from overlapping robots. Without
+// robots there is no tree to find, which is 0 like the safety factor of no robots.
+pub fn part_2_variance(input: &Input) -> u64 {
+    let map_height = params::get("height", MAP_HEIGHT);
+    let map_width = params::get("width", MAP_WIDTH);
+    let Some(sighting) = find_tree_by_variance(&input.robots, map_height, map_width) else {
+        return 0;
+    };
+    println!(
+        "Tree after {} seconds, confidence {:.2}",
+        sighting.seconds, sighting.confidence
+    );
+    sighting.seconds
+}
+
+// the state after `seconds` seconds, which can be saved to a checkpoint and resumed from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Simulation {
+    pub map_height: i64,
+    pub map_width: i64,
+    pub robots: Vec<Robot>,
+    pub seconds: u64,
+}
+
+impl Simulation {
+    pub fn new(input: &Input) -> Self {
+        Simulation {
+            map_height: params::get("height", MAP_HEIGHT),
+            map_width: params::get("width", MAP_WIDTH),
+            robots: input.robots.clone(),
+            seconds: 0,
+        }
+    }
+
+    // resumes from the checkpoint in the `resume` param if there is one
+    pub fn start(input: &Input) -> io::Result<Self> {
+        let path: String = params::get("resume", String::new());
+        if path.is_empty() {
+            return Ok(Simulation::new(input));
+        }
+        checkpoint::load(Path::new(&path), 2024, 14, VERSION).map_err(|err| {
+            io::Error::new(err.kind(), format!("Cannot resume from {}: {}", path, err))
+        })
+    }
+
+    pub fn step(&mut self) {
+        self.robots = run_simulation(&self.robots, self.map_height, self.map_width);
+        self.seconds += 1;
+    }
+
+    pub fn advance(&mut self, seconds: u64) {
+        let (map_height, map_width) = (self.map_height, self.map_width);
+        for robot in &mut self.robots {
+            robot.p = position_at(robot, seconds, map_height, map_width);
+        }
+        self.seconds += seconds;
+    }
+
+    pub fn safety_factor(&self) -> u64 {
+        let quadrants = robots_per_quardant(&self.robots, self.map_height, self.map_width);
+        quadrants.q1 * quadrants.q2 * quadrants.q3 * quadrants.q4
+    }
+
+    pub fn to_checkpoint(&self) -> String {
+        checkpoint::to_json(2024, 14, VERSION, self)
+    }
+
+    pub fn from_checkpoint(json: &str) -> io::Result<Self> {
+        checkpoint::from_json(2024, 14, VERSION, json)
+    }
+}
+
+impl repl::Explorer for Simulation {
+    fn show(&self) {
+        println!("After {} seconds:", self.seconds);
+        print_robots(&self.robots, self.map_height, self.map_width);
+    }
+
+    fn step(&mut self, steps: usize) -> Result<String, String> {
+        for _ in 0..steps {
+            Simulation::step(self);
+        }
+
+        let overlapping = match find_christmas_tree(&self.robots) {
+            true => "no robots overlap",
+            false => "robots overlap",
+        };
+        Ok(format!(
+            "After {} seconds: safety factor {}, {}",
+            self.seconds,
+            self.safety_factor(),
+            overlapping
+        ))
+    }
+
+    fn checkpoint(&self) -> Result<String, String> {
+        Ok(self.to_checkpoint())
+    }
+
+    fn restore(&mut self, json: &str) -> Result<String, String> {
+        *self = Simulation::from_checkpoint(json).map_err(|err| err.to_string())?;
+        Ok(format!("Resumed after {} seconds", self.seconds))
+    }
+}
+
+pub fn explorer(input: &str) -> Result<Box<dyn repl::Explorer>, ParseError> {
+    let input: Input = input.parse()?;
+    Ok(Box::new(Simulation::new(&input)))
+}
+
+pub fn solve_part_1(filepath: &str) -> io::Result<u64> {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> io::Result<u64> {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        let map_height = 7;
+        let map_width = 11;
+
+        let mut robots = input.robots;
+
+        for _ in 0..100 {
+            robots = run_simulation(&robots, map_height, map_width);
+        }
+
+        println!("{:?}", robots);
+        print_robots(&robots, map_height, map_width);
+        let quadrants = robots_per_quardant(&robots, map_height, map_width);
+
+        let res = quadrants.q1 * quadrants.q2 * quadrants.q3 * quadrants.q4;
+        assert_eq!(res, 12);
+    }
+
+    #[test]
+    fn test_example_part1_with_params() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        let mut example_params = params::Params::new();
+        example_params.set("height", "7");
+        example_params.set("width", "11");
+
+        assert_eq!(
+            params::with_params(&example_params, || part_1(&input)).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        let mut example_params = params::Params::new();
+        example_params.set("height", "7");
+        example_params.set("width", "11");
+
+        let mut simulation = params::with_params(&example_params, || Simulation::new(&input));
+        for _ in 0..40 {
+            simulation.step();
+        }
+        let checkpoint = simulation.to_checkpoint();
+        assert_eq!(
+            Simulation::from_checkpoint(&checkpoint).unwrap(),
+            simulation
+        );
+        assert!(crate::y2024::d15::Simulation::from_checkpoint(&checkpoint).is_err());
+
+        let path = std::env::temp_dir().join(format!("aoc-d14-{}.json", std::process::id()));
+        std::fs::write(&path, checkpoint).unwrap();
+        let mut resume = params::Params::new();
+        resume.set("resume", path.to_str().unwrap());
+
+        assert_eq!(params::with_params(&resume, || part_1(&input)).unwrap(), 12);
+        resume.set("seconds", "30");
+        let err = params::with_params(&resume, || part_1(&input)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Checkpoint after 40 seconds is past 30 seconds"
+        );
+        std::fs::remove_file(path).unwrap();
+
+        // a checkpoint that is gone is reported like a bad input
+        let registry = crate::registry::Registry::new();
+        let solution = registry.get(2024, 14).unwrap();
+        let text = std::fs::read_to_string("inputs/2024/input_14_test").unwrap();
+        let err = params::with_params(&resume, || solution.solve(1, &text)).unwrap_err();
+        assert!(err.to_string().starts_with("Cannot resume from"));
+    }
+
+    #[test]
+    fn test_position_at() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        let mut robots = input.robots.clone();
+        for seconds in 0..200 {
+            for (robot, moved) in input.robots.iter().zip(&robots) {
+                assert_eq!(position_at(robot, seconds, 7, 11), moved.p);
+            }
+            robots = run_simulation(&robots, 7, 11);
+        }
+
+        let robot = Robot {
+            p: Point::new(2, 4),
+            v: Vec2::new(2, -3),
+        };
+        assert_eq!(position_at(&robot, 5, 7, 11), Point::new(1, 3));
+        // a whole number of laps of both axes later
+        assert_eq!(position_at(&robot, 77 * 10_000_000_000_000, 7, 11), robot.p);
+        let seconds = 1_000_000_000_000_000;
+        assert_eq!(
+            position_at(&robot, seconds, MAP_HEIGHT, MAP_WIDTH),
+            position_at(&robot, seconds % 10403, MAP_HEIGHT, MAP_WIDTH)
+        );
+    }
+
+    #[test]
+    fn test_count_cycles() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        for robot in &input.robots {
+            let cycles = count_cycles(robot, 7, 11);
+            assert_eq!(position_at(robot, cycles, 7, 11), robot.p);
+            assert!((1..cycles).all(|seconds| position_at(robot, seconds, 7, 11) != robot.p));
+        }
+
+        let still = Robot {
+            p: Point::new(1, 1),
+            v: Vec2::new(11, -7),
+        };
+        assert_eq!(count_cycles(&still, 7, 11), 1);
+        let robot = Robot {
+            p: Point::new(1, 1),
+            v: Vec2::new(3, 0),
+        };
+        assert_eq!(count_cycles(&robot, MAP_HEIGHT, MAP_WIDTH), 101);
+    }
+
+    // robots gathering in a square of `spread` tiles around the middle after `seconds` seconds,
+    // plus `noise` robots anywhere, placed by a fixed linear congruential generator
+    fn gathering(
+        map_height: i64,
+        map_width: i64,
+        seconds: i64,
+        spread: i64,
+        noise: usize,
+    ) -> Vec<Robot> {
+        let mut state: u64 = 12345;
+        let mut random = |below: i64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % below as u64) as i64
+        };
+
+        let mut robots = Vec::new();
+        for i in 0..300 + noise {
+            let v = Vec2::new(
+                random(2 * map_width) - map_width,
+                random(2 * map_height) - map_height,
+            );
+            let p = match i < 300 {
+                true => {
+                    let end = Point::new(
+                        map_width / 2 + random(spread) - spread / 2,
+                        map_height / 2 + random(spread) - spread / 2,
+                    );
+                    let start = end - v * seconds;
+                    Point::new(
+                        start.x.rem_euclid(map_width),
+                        start.y.rem_euclid(map_height),
+                    )
+                }
+                false => Point::new(random(map_width), random(map_height)),
+            };
+            robots.push(Robot { p, v });
+        }
+        robots
+    }
+
+    #[test]
+    fn test_find_tree_by_variance() {
+        let robots = gathering(MAP_HEIGHT, MAP_WIDTH, 6876, 15, 200);
+        let sighting = find_tree_by_variance(&robots, MAP_HEIGHT, MAP_WIDTH).unwrap();
+        assert_eq!(sighting.seconds, 6876);
+        assert!(sighting.confidence > 0.5, "{:?}", sighting);
+
+        // the sizes share a factor of 10, so the time is only known modulo 60
+        let robots = gathering(20, 30, 47, 5, 50);
+        let sighting = find_tree_by_variance(&robots, 20, 30).unwrap();
+        assert_eq!(sighting.seconds, 47);
+
+        // nothing ever gathers
+        let robots = gathering(MAP_HEIGHT, MAP_WIDTH, 100, MAP_HEIGHT, 0);
+        let sighting = find_tree_by_variance(&robots, MAP_HEIGHT, MAP_WIDTH).unwrap();
+        assert!(sighting.confidence < 0.2, "{:?}", sighting);
+
+        assert_eq!(find_tree_by_variance(&[], MAP_HEIGHT, MAP_WIDTH), None);
+        let nobody = Input { robots: Vec::new() };
+        assert_eq!(part_2_variance(&nobody), 0);
+        assert_eq!(part_1(&nobody).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test_2");
+
+        let map_height = 7;
+        let map_width = 11;
+
+        let robots = input.robots;
+        println!("Number of robots: {}", robots.len());
+
+        print_robots(&robots, map_height, map_width);
+        let target = find_christmas_tree(&robots);
+
+        assert!(target);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_14_test");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
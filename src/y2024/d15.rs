@@ -0,0 +1,815 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint;
+use crate::params;
+use crate::repl;
+use crate::trace;
+use crate::utils::{self, Direction, Grid, ParseError, Position};
+
+pub const VERSION: &str = "2";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Tile {
+    Robot,
+    Wall,
+    Empty,
+    Box,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum BoxSide {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum TileWide {
+    Robot,
+    Wall,
+    Empty,
+    Box(BoxSide),
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match self {
+            Tile::Robot => '@',
+            Tile::Wall => '#',
+            Tile::Empty => '.',
+            Tile::Box => 'O',
+        };
+        write!(f, "{}", glyph)
+    }
+}
+
+impl fmt::Display for TileWide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match self {
+            TileWide::Robot => '@',
+            TileWide::Wall => '#',
+            TileWide::Empty => '.',
+            TileWide::Box(BoxSide::Left) => '[',
+            TileWide::Box(BoxSide::Right) => ']',
+        };
+        write!(f, "{}", glyph)
+    }
+}
+
+// map rows are stored top to bottom, the way they appear in the puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub map: Grid<Tile>,
+    pub moves: Vec<Direction>,
+}
+
+impl FromStr for Input {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map_lines = Vec::new();
+        let mut robot_moves = Vec::new();
+
+        for line in s.lines() {
+            if line.starts_with("#") {
+                map_lines.push(line);
+            } else if !line.is_empty() {
+                for char in line.chars() {
+                    match Direction::from_arrow(char) {
+                        Some(robot_move) => robot_moves.push(robot_move),
+                        None => return Err(ParseError::new(format!("Unknown move: {:?}", char))),
+                    }
+                }
+            }
+        }
+
+        if map_lines.is_empty() {
+            return Err(ParseError::new("Warehouse map is empty"));
+        }
+        let tiles = Grid::parse(&map_lines.join("\n"), |char| match char {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Empty),
+            '@' => Some(Tile::Robot),
+            'O' => Some(Tile::Box),
+            _ => None,
+        })?;
+        Ok(Input {
+            map: tiles,
+            moves: robot_moves,
+        })
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.map)?;
+        for robot_move in &self.moves {
+            write!(f, "{}", robot_move.arrow())?;
+        }
+        writeln!(f)
+    }
+}
+
+// simulation works on maps with the bottom row first
+fn load_elements(input: &Input) -> Grid<Tile> {
+    input.map.flip_rows()
+}
+
+pub fn print_tiles(tiles: &Grid<Tile>) {
+    print!("{}", tiles.flip_rows());
+}
+
+fn find_robot(tiles: &Grid<Tile>) -> Option<Position> {
+    tiles.position(|tile| *tile == Tile::Robot)
+}
+
+fn find_robot_part2(tiles: &Grid<TileWide>) -> Option<Position> {
+    tiles.position(|tile| *tile == TileWide::Robot)
+}
+
+fn perform_move(tiles: &Grid<Tile>, robot_move: Direction) -> Grid<Tile> {
+    let mut new_map = {
+        let _span = trace::span("clone_map");
+        tiles.clone()
+    };
+
+    let (robot_i, robot_j) = find_robot(tiles).unwrap();
+    // println!("Current robot pos: {} {}", robot_i, robot_j);
+    // println!("Trying to move {:?}", robot_move);
+
+    let map_height = tiles.height();
+    let map_width = tiles.width();
+
+    match robot_move {
+        Direction::North => {
+            for i in robot_i + 1..map_height {
+                match tiles[(i, robot_j)] {
+                    Tile::Robot => (),
+                    Tile::Wall => {
+                        break;
+                    }
+                    Tile::Empty => {
+                        for new_tile_i in (robot_i + 1..=i).rev() {
+                            new_map.swap((new_tile_i, robot_j), (new_tile_i - 1, robot_j));
+                        }
+                        break;
+                    }
+                    Tile::Box => (),
+                }
+            }
+        }
+        Direction::South => {
+            for i in (0..robot_i).rev() {
+                match tiles[(i, robot_j)] {
+                    Tile::Robot => (),
+                    Tile::Wall => {
+                        break;
+                    }
+                    Tile::Empty => {
+                        for new_tile_i in i..robot_i {
+                            new_map.swap((new_tile_i, robot_j), (new_tile_i + 1, robot_j));
+                        }
+                        break;
+                    }
+                    Tile::Box => (),
+                }
+            }
+        }
+        Direction::West => {
+            for j in (0..robot_j).rev() {
+                match tiles[(robot_i, j)] {
+                    Tile::Robot => (),
+                    Tile::Wall => {
+                        break;
+                    }
+                    Tile::Empty => {
+                        for new_tile_j in j..robot_j {
+                            new_map.swap((robot_i, new_tile_j), (robot_i, new_tile_j + 1));
+                        }
+                        break;
+                    }
+                    Tile::Box => (),
+                }
+            }
+        }
+        Direction::East => {
+            for j in (robot_j + 1)..map_width {
+                match tiles[(robot_i, j)] {
+                    Tile::Robot => (),
+                    Tile::Wall => {
+                        break;
+                    }
+                    Tile::Empty => {
+                        for new_tile_j in (robot_j + 1..=j).rev() {
+                            new_map.swap((robot_i, new_tile_j), (robot_i, new_tile_j - 1));
+                        }
+                        break;
+                    }
+                    Tile::Box => (),
+                }
+            }
+        }
+    }
+
+    new_map
+}
+
+fn calculate_gps(tiles: &Grid<Tile>) -> u64 {
+    let mut res = 0;
+
+    for (i, tile_row) in tiles.rows().rev().enumerate() {
+        for (j, tile) in tile_row.iter().enumerate() {
+            if *tile == Tile::Box {
+                res += 100 * i + j;
+            }
+        }
+    }
+
+    res.try_into().unwrap()
+}
+
+pub fn part_1(input: &Input) -> io::Result<u64> {
+    let mut simulation = Simulation::start(input)?;
+
+    while let Some(robot_move) = simulation.moves.get(simulation.next_move) {
+        let _span = trace::span("perform_move");
+        simulation.tiles = perform_move(&simulation.tiles, *robot_move);
+        simulation.next_move += 1;
+    }
+
+    print_tiles(&simulation.tiles);
+
+    Ok(calculate_gps(&simulation.tiles))
+}
+
+fn load_elements_part_2(input: &Input) -> Grid<TileWide> {
+    let mut tiles = Vec::new();
+
+    for row in load_elements(input).rows() {
+        let mut tiles_row = Vec::new();
+        for tile in row {
+            match tile {
+                Tile::Wall => {
+                    tiles_row.push(TileWide::Wall);
+                    tiles_row.push(TileWide::Wall);
+                }
+                Tile::Empty => {
+                    tiles_row.push(TileWide::Empty);
+                    tiles_row.push(TileWide::Empty);
+                }
+                Tile::Robot => {
+                    tiles_row.push(TileWide::Robot);
+                    tiles_row.push(TileWide::Empty);
+                }
+                Tile::Box => {
+                    tiles_row.push(TileWide::Box(BoxSide::Left));
+                    tiles_row.push(TileWide::Box(BoxSide::Right));
+                }
+            }
+        }
+        tiles.push(tiles_row);
+    }
+
+    Grid::from_rows(tiles).expect("Doubling the tiles keeps the map rectangular")
+}
+
+pub fn print_tiles_part2(tiles: &Grid<TileWide>) {
+    print!("{}", tiles.flip_rows());
+}
+
+fn calculate_move_up(tiles: &Grid<TileWide>, start_i: usize, j: usize) -> Option<(usize, usize)> {
+    let map_height = tiles.height();
+    for i in start_i + 1..map_height {
+        match tiles[(i, j)] {
+            TileWide::Robot => panic!("Robot shouldn't be on way {} {}", i, j),
+            TileWide::Wall => {
+                return None;
+            }
+            TileWide::Empty => {
+                return Some((i, j));
+            }
+            TileWide::Box(_box_side) => (),
+        }
+    }
+    None
+}
+
+fn find_blocks_to_move_up(
+    tiles: &Grid<TileWide>,
+    start_i: usize,
+    start_j: usize,
+) -> BTreeMap<(usize, usize), Option<(usize, usize)>> {
+    let mut blocks_to_move = BTreeMap::new();
+
+    let mut blocks_to_analyse = Vec::new();
+    blocks_to_analyse.push((start_i, start_j));
+    while let Some((current_i, current_j)) = blocks_to_analyse.pop() {
+        if blocks_to_move.contains_key(&(current_i, current_j)) {
+            continue;
+        }
+        let current_tile = tiles[(current_i, current_j)];
+        if let TileWide::Box(current_side) = current_tile {
+            if tiles[(current_i - 1, current_j)] == TileWide::Box(current_side) {
+                continue;
+            }
+        }
+        match calculate_move_up(tiles, current_i, current_j) {
+            Some((end_i, end_j)) => {
+                blocks_to_move.insert((current_i, current_j), Some((end_i, end_j)));
+                for i in current_i + 1..end_i {
+                    if let TileWide::Box(box_side) = tiles[(i, current_j)] {
+                        match box_side {
+                            BoxSide::Left => {
+                                blocks_to_analyse.push((i, current_j + 1));
+                            }
+                            BoxSide::Right => {
+                                blocks_to_analyse.push((i, current_j - 1));
+                            }
+                        };
+                    }
+                }
+            }
+            None => {
+                // println!("Cannot move: {} {}", current_i, current_j);
+                blocks_to_move.insert((current_i, current_j), None);
+                break;
+            }
+        }
+    }
+
+    blocks_to_move
+}
+
+fn calculate_move_down(tiles: &Grid<TileWide>, start_i: usize, j: usize) -> Option<(usize, usize)> {
+    for i in (0..start_i).rev() {
+        match tiles[(i, j)] {
+            TileWide::Robot => panic!("Robot shouldn't be on way {} {}", i, j),
+            TileWide::Wall => {
+                return None;
+            }
+            TileWide::Empty => {
+                return Some((i, j));
+            }
+            TileWide::Box(_box_side) => (),
+        }
+    }
+    None
+}
+
+fn find_blocks_to_move_down(
+    tiles: &Grid<TileWide>,
+    start_i: usize,
+    start_j: usize,
+) -> BTreeMap<(usize, usize), Option<(usize, usize)>> {
+    let mut blocks_to_move = BTreeMap::new();
+
+    let mut blocks_to_analyse = Vec::new();
+    blocks_to_analyse.push((start_i, start_j));
+    while let Some((current_i, current_j)) = blocks_to_analyse.pop() {
+        if blocks_to_move.contains_key(&(current_i, current_j)) {
+            continue;
+        }
+        let current_tile = tiles[(current_i, current_j)];
+        if let TileWide::Box(current_side) = current_tile {
+            if tiles[(current_i + 1, current_j)] == TileWide::Box(current_side) {
+                continue;
+            }
+        }
+        match calculate_move_down(tiles, current_i, current_j) {
+            Some((end_i, end_j)) => {
+                blocks_to_move.insert((current_i, current_j), Some((end_i, end_j)));
+                for i in (end_i + 1..current_i).rev() {
+                    if let TileWide::Box(box_side) = tiles[(i, current_j)] {
+                        match box_side {
+                            BoxSide::Left => {
+                                blocks_to_analyse.push((i, current_j + 1));
+                            }
+                            BoxSide::Right => {
+                                blocks_to_analyse.push((i, current_j - 1));
+                            }
+                        };
+                    };
+                }
+            }
+            None => {
+                // println!("Cannot move: {} {}", current_i, current_j);
+                blocks_to_move.insert((current_i, current_j), None);
+                break;
+            }
+        }
+    }
+
+    blocks_to_move
+}
+
+fn filter_overlaps_up(
+    blocks_to_move: &BTreeMap<(usize, usize), Option<(usize, usize)>>,
+) -> BTreeMap<(usize, usize), (usize, usize)> {
+    let mut filtered_map = BTreeMap::new();
+    for ((i, j), block) in blocks_to_move {
+        if let Some((target_i, target_j)) = block {
+            filtered_map.insert((*i, *j), (*target_i, *target_j));
+        }
+    }
+
+    let mut block_to_delete = Vec::new();
+    for ((start_i, start_j), (end_i, _)) in &filtered_map {
+        for ((another_start_i, another_start_j), (another_end_i, _)) in &filtered_map {
+            if start_j == another_start_j
+                && start_i != another_start_i
+                && another_end_i >= end_i
+                && another_start_i <= start_i
+            {
+                block_to_delete.push((*start_i, *start_j));
+            }
+        }
+    }
+
+    for block in block_to_delete {
+        filtered_map.remove(&block);
+    }
+
+    filtered_map
+}
+
+fn filter_overlaps_down(
+    blocks_to_move: &BTreeMap<(usize, usize), Option<(usize, usize)>>,
+) -> BTreeMap<(usize, usize), (usize, usize)> {
+    let mut filtered_map = BTreeMap::new();
+    for ((i, j), block) in blocks_to_move {
+        if let Some((target_i, target_j)) = block {
+            filtered_map.insert((*i, *j), (*target_i, *target_j));
+        }
+    }
+
+    let mut block_to_delete = Vec::new();
+    for ((start_i, start_j), (end_i, _)) in &filtered_map {
+        for ((another_start_i, another_start_j), (another_end_i, _)) in &filtered_map {
+            if start_j == another_start_j
+                && start_i != another_start_i
+                && another_end_i <= end_i
+                && another_start_i >= start_i
+            {
+                block_to_delete.push((*start_i, *start_j));
+            }
+        }
+    }
+
+    for block in block_to_delete {
+        filtered_map.remove(&block).unwrap();
+    }
+
+    filtered_map
+}
+
+fn perform_move_part2(tiles: &Grid<TileWide>, robot_move: Direction) -> Grid<TileWide> {
+    let mut new_map = {
+        let _span = trace::span("clone_map");
+        tiles.clone()
+    };
+
+    let (robot_i, robot_j) = find_robot_part2(tiles).unwrap();
+    // println!("Current robot pos: {} {}", robot_i, robot_j);
+    // println!("Trying to move {:?}", robot_move);
+
+    let map_width = tiles.width();
+
+    match robot_move {
+        Direction::North => {
+            match tiles[(robot_i + 1, robot_j)] {
+                TileWide::Robot => (),
+                TileWide::Wall => (),
+                TileWide::Empty => {
+                    // easy case just move
+                    new_map.swap((robot_i, robot_j), (robot_i + 1, robot_j));
+                }
+                TileWide::Box(box_side) => {
+                    // can move -> gather all the blocks to move
+                    // if side is the same do nothing
+                    // if sides are opossite calculate also
+                    //  for a "new" block to be moved
+                    let mut blocks_to_move = find_blocks_to_move_up(tiles, robot_i + 1, robot_j);
+                    let block_to_move_other_side = if box_side == BoxSide::Left {
+                        find_blocks_to_move_up(tiles, robot_i + 1, robot_j + 1)
+                    } else {
+                        find_blocks_to_move_up(tiles, robot_i + 1, robot_j - 1)
+                    };
+                    blocks_to_move.extend(block_to_move_other_side);
+                    for ((_block_i, _block_j), target_block) in &blocks_to_move {
+                        match target_block {
+                            Some((_, _)) => (),
+                            None => {
+                                // println!("Cannot move: {:?}", (block_i, block_j));
+                                return tiles.clone();
+                            }
+                        }
+                    }
+                    let blocks_to_move = filter_overlaps_up(&blocks_to_move);
+                    for ((block_i, block_j), (target_i, _target_j)) in &blocks_to_move {
+                        for i in (*block_i + 1..=*target_i).rev() {
+                            new_map.swap((i, *block_j), (i - 1, *block_j));
+                        }
+                    }
+                    new_map.swap((robot_i, robot_j), (robot_i + 1, robot_j));
+                }
+            }
+        }
+        Direction::South => {
+            match tiles[(robot_i - 1, robot_j)] {
+                TileWide::Robot => (),
+                TileWide::Wall => (),
+                TileWide::Empty => {
+                    new_map.swap((robot_i, robot_j), (robot_i - 1, robot_j));
+                }
+                TileWide::Box(box_side) => {
+                    // can move -> gather all the blocks to move
+                    // if side is the same do nothing
+                    // if sides are opossite calculate also
+                    //  for a "new" block to be moved
+                    let mut blocks_to_move = find_blocks_to_move_down(tiles, robot_i - 1, robot_j);
+                    let block_to_move_other_side = match box_side {
+                        BoxSide::Left => find_blocks_to_move_down(tiles, robot_i - 1, robot_j + 1),
+                        BoxSide::Right => find_blocks_to_move_down(tiles, robot_i - 1, robot_j - 1),
+                    };
+                    blocks_to_move.extend(block_to_move_other_side);
+                    for ((_block_i, _block_j), target_block) in &blocks_to_move {
+                        match target_block {
+                            Some((_, _)) => (),
+                            None => {
+                                // println!("Cannot move: {:?}", (block_i, block_j));
+                                return tiles.clone();
+                            }
+                        }
+                    }
+                    let blocks_to_move = filter_overlaps_down(&blocks_to_move);
+                    for ((block_i, block_j), (target_i, _target_j)) in &blocks_to_move {
+                        for i in (*target_i + 1)..=*block_i {
+                            new_map.swap((i, *block_j), (i - 1, *block_j));
+                        }
+                    }
+                    new_map.swap((robot_i, robot_j), (robot_i - 1, robot_j));
+                }
+            }
+        }
+        Direction::West => {
+            for j in (0..robot_j).rev() {
+                match tiles[(robot_i, j)] {
+                    TileWide::Robot => (),
+                    TileWide::Wall => {
+                        break;
+                    }
+                    TileWide::Empty => {
+                        for new_tile_j in j..robot_j {
+                            new_map.swap((robot_i, new_tile_j), (robot_i, new_tile_j + 1));
+                        }
+                        break;
+                    }
+                    // don't need to be updated on l-r move
+                    TileWide::Box(_) => (),
+                }
+            }
+        }
+        Direction::East => {
+            for j in (robot_j + 1)..map_width {
+                match tiles[(robot_i, j)] {
+                    TileWide::Robot => (),
+                    TileWide::Wall => {
+                        break;
+                    }
+                    TileWide::Empty => {
+                        for new_tile_j in (robot_j + 1..=j).rev() {
+                            new_map.swap((robot_i, new_tile_j), (robot_i, new_tile_j - 1));
+                        }
+                        break;
+                    }
+                    // don't need to be updated on l-r move
+                    TileWide::Box(_) => (),
+                }
+            }
+        }
+    }
+
+    new_map
+}
+
+fn calculate_gps_part_2(tiles: &Grid<TileWide>) -> u64 {
+    let mut res = 0;
+
+    for (i, tile_row) in tiles.rows().rev().enumerate() {
+        for (j, tile) in tile_row.iter().enumerate() {
+            if *tile == TileWide::Box(BoxSide::Left) {
+                res += 100 * i + j;
+            }
+        }
+    }
+
+    res.try_into().unwrap()
+}
+
+pub fn part_2(input: &Input) -> io::Result<u64> {
+    let mut simulation = Simulation::start(input)?;
+
+    println!("\nInitial state\n");
+    print_tiles_part2(&simulation.wide_tiles);
+    while let Some(robot_move) = simulation.moves.get(simulation.next_move) {
+        let _span = trace::span("perform_move");
+        simulation.wide_tiles = perform_move_part2(&simulation.wide_tiles, *robot_move);
+        simulation.next_move += 1;
+
+        // println!("\nMove {} {:?}:\n", _i, robot_move);
+        // print_tiles_part2(&tiles);
+    }
+
+    println!("\nFinal state:\n");
+    print_tiles_part2(&simulation.wide_tiles);
+
+    Ok(calculate_gps_part_2(&simulation.wide_tiles))
+}
+
+// both warehouses after `next_move` moves, which can be saved to a checkpoint and resumed from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Simulation {
+    pub moves: Vec<Direction>,
+    pub next_move: usize,
+    pub tiles: Grid<Tile>,
+    pub wide_tiles: Grid<TileWide>,
+}
+
+impl Simulation {
+    pub fn new(input: &Input) -> Self {
+        Simulation {
+            moves: input.moves.clone(),
+            next_move: 0,
+            tiles: load_elements(input),
+            wide_tiles: load_elements_part_2(input),
+        }
+    }
+
+    // resumes from the checkpoint in the `resume` param if there is one
+    pub fn start(input: &Input) -> io::Result<Self> {
+        let path: String = params::get("resume", String::new());
+        if path.is_empty() {
+            return Ok(Simulation::new(input));
+        }
+        checkpoint::load(Path::new(&path), 2024, 15, VERSION).map_err(|err| {
+            io::Error::new(err.kind(), format!("Cannot resume from {}: {}", path, err))
+        })
+    }
+
+    pub fn to_checkpoint(&self) -> String {
+        checkpoint::to_json(2024, 15, VERSION, self)
+    }
+
+    pub fn from_checkpoint(json: &str) -> io::Result<Self> {
+        checkpoint::from_json(2024, 15, VERSION, json)
+    }
+}
+
+impl repl::Explorer for Simulation {
+    fn show(&self) {
+        println!("After {} of {} moves:", self.next_move, self.moves.len());
+        print_tiles(&self.tiles);
+        println!();
+        print_tiles_part2(&self.wide_tiles);
+    }
+
+    fn step(&mut self, steps: usize) -> Result<String, String> {
+        if self.next_move == self.moves.len() {
+            return Err("All moves are done".to_string());
+        }
+        for robot_move in self.moves.iter().skip(self.next_move).take(steps) {
+            self.tiles = perform_move(&self.tiles, *robot_move);
+            self.wide_tiles = perform_move_part2(&self.wide_tiles, *robot_move);
+            self.next_move += 1;
+        }
+        Ok(format!(
+            "After {} of {} moves: GPS {}, wide GPS {}",
+            self.next_move,
+            self.moves.len(),
+            calculate_gps(&self.tiles),
+            calculate_gps_part_2(&self.wide_tiles)
+        ))
+    }
+
+    fn checkpoint(&self) -> Result<String, String> {
+        Ok(self.to_checkpoint())
+    }
+
+    fn restore(&mut self, json: &str) -> Result<String, String> {
+        *self = Simulation::from_checkpoint(json).map_err(|err| err.to_string())?;
+        Ok(format!(
+            "Resumed after {} of {} moves",
+            self.next_move,
+            self.moves.len()
+        ))
+    }
+}
+
+pub fn explorer(input: &str) -> Result<Box<dyn repl::Explorer>, ParseError> {
+    let input: Input = input.parse()?;
+    Ok(Box::new(Simulation::new(&input)))
+}
+
+pub fn solve_part_1(filepath: &str) -> io::Result<u64> {
+    part_1(&utils::load_input(filepath))
+}
+
+pub fn solve_part_2(filepath: &str) -> io::Result<u64> {
+    part_2(&utils::load_input(filepath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_15_test").unwrap(), 2028);
+    }
+
+    #[test]
+    fn test_bigger_example_part1() {
+        assert_eq!(solve_part_1("inputs/2024/input_15_test_2").unwrap(), 10092);
+    }
+
+    #[test]
+    fn test_simulation_steps() {
+        use crate::repl::Explorer;
+
+        let input: Input = utils::load_input("inputs/2024/input_15_test");
+        let mut simulation = Simulation::new(&input);
+        let moves = input.moves.len();
+
+        assert!(simulation.step(moves - 1).is_ok());
+        assert_eq!(
+            simulation.step(5),
+            Ok(format!(
+                "After {} of {} moves: GPS 2028, wide GPS 1751",
+                moves, moves
+            ))
+        );
+        assert!(simulation.step(1).is_err());
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint() {
+        use crate::repl::Explorer;
+
+        let input: Input = utils::load_input("inputs/2024/input_15_test_2");
+        let mut simulation = Simulation::new(&input);
+        simulation.step(300).unwrap();
+        let checkpoint = simulation.to_checkpoint();
+        assert_eq!(
+            Simulation::from_checkpoint(&checkpoint).unwrap(),
+            simulation
+        );
+
+        let path = std::env::temp_dir().join(format!("aoc-d15-{}.json", std::process::id()));
+        std::fs::write(&path, checkpoint).unwrap();
+        let mut resume = params::Params::new();
+        resume.set("resume", path.to_str().unwrap());
+
+        assert_eq!(
+            params::with_params(&resume, || part_1(&input)).unwrap(),
+            10092
+        );
+        assert_eq!(
+            params::with_params(&resume, || part_2(&input)).unwrap(),
+            9021
+        );
+        std::fs::remove_file(path).unwrap();
+
+        // a checkpoint that is gone is reported like a bad input
+        let registry = crate::registry::Registry::new();
+        let solution = registry.get(2024, 15).unwrap();
+        let text = std::fs::read_to_string("inputs/2024/input_15_test_2").unwrap();
+        let err = params::with_params(&resume, || solution.solve(2, &text)).unwrap_err();
+        assert!(err.to_string().starts_with("Cannot resume from"));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_15_test_3").unwrap(), 618);
+    }
+
+    #[test]
+    fn test_another_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_15_test_4").unwrap(), 719);
+    }
+
+    #[test]
+    fn test_bigger_example_part2() {
+        assert_eq!(solve_part_2("inputs/2024/input_15_test_2").unwrap(), 9021);
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let input: Input = utils::load_input("inputs/2024/input_15_test_2");
+        assert_eq!(input.to_string().parse::<Input>(), Ok(input));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::registry::{solution, Solution};
+
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;
+
+pub fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2024, 1, d01, "Historian Hysteria"),
+        solution!(2024, 2, d02, "Red-Nosed Reports"),
+        solution!(2024, 3, d03, "Mull It Over"),
+        solution!(2024, 4, d04, "Ceres Search"),
+        solution!(2024, 5, d05, "Print Queue"),
+        solution!(2024, 6, d06, "Guard Gallivant"),
+        solution!(2024, 7, d07, "Bridge Repair"),
+        solution!(2024, 8, d08, "Resonant Collinearity"),
+        solution!(2024, 9, d09, "Disk Fragmenter"),
+        solution!(2024, 10, d10, "Hoof It",
+            1 => "layered": part_1_layered,
+            2 => "layered": part_2_layered,
+        ),
+        solution!(2024, 11, d11, "Plutonian Pebbles"),
+        solution!(2024, 12, d12, "Garden Groups"),
+        solution!(2024, 13, d13, "Claw Contraption",
+            1 => "brute_force": part_1_brute_force,
+        ),
+        solution!(2024, 14, d14, "Restroom Redoubt"; repl: explorer,
+            2 => "variance": part_2_variance,
+        ),
+        solution!(2024, 15, d15, "Warehouse Woes"; repl: explorer),
+    ]
+}